@@ -0,0 +1,410 @@
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use crate::configuration::code::{decode_galaxy_code, CodeError};
+use crate::configuration::parameters::{Parameter, PARAMETERS};
+use crate::configuration::resources::*;
+use crate::configuration::validation::InvalidConfiguration;
+
+pub const USAGE: &str = "\
+Usage: spiral-galaxy-map [options]
+
+Galaxy:
+  --star-count <n>, --star-size <n>, --dimming-speed <n>, --velocity <n>,
+  --semi-axis-major <n>, --orbit-rotation <n>, --orbit-density <n>,
+  --stars-per-orbit <n>
+                        set a parameter, within the limits of the panel
+  --dust, --no-dust, --h2, --no-h2, --filament, --no-filament
+                        show or leave out a kind of body
+  --seed <n>            seed of the random spread of orbits
+  --parsecs-per-scene-unit <n>
+                        scale of the map, 700 by default
+  --code <code>         start from a galaxy code
+  --preset <path>       configuration file applied on start and whenever it's
+                        saved, galaxy.ron by default
+
+Window:
+  --width <px>, --height <px>
+  --fullscreen
+  --hide-ui             start without the panels and labels, I shows them
+
+  --help                show this message";
+
+#[derive(Debug)]
+pub enum CliError {
+    UnknownFlag(String),
+    MissingValue(String),
+    InvalidValue { flag: String, value: String },
+    OutOfRange { flag: String, value: f32, min: f32, max: f32 },
+    Code(CodeError),
+    Preset { path: PathBuf, error: String },
+    Invalid(InvalidConfiguration),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CliError::UnknownFlag(flag) => write!(f, "unknown option {flag}"),
+            CliError::MissingValue(flag) => write!(f, "{flag} needs a value"),
+            CliError::InvalidValue { flag, value } => {
+                write!(f, "{value} isn't a valid value for {flag}")
+            }
+            CliError::OutOfRange {
+                flag,
+                value,
+                min,
+                max,
+            } => write!(f, "{flag} must be between {min} and {max}, got {value}"),
+            CliError::Code(error) => write!(f, "invalid galaxy code: {error}"),
+            CliError::Preset { path, error } => {
+                write!(f, "couldn't load {}: {}", path.display(), error)
+            }
+            CliError::Invalid(errors) => write!(f, "invalid configuration: {errors}"),
+        }
+    }
+}
+
+/// Launch options given on the command line
+pub struct Cli {
+    pub help: bool,
+    pub configuration: Configuration,
+    pub watch: ConfigurationWatch,
+    pub units: MapUnits,
+    pub width: Option<f32>,
+    pub height: Option<f32>,
+    pub fullscreen: bool,
+    pub hide_ui: bool,
+}
+
+impl Cli {
+    /// Reads the options, `args` excluding the program name.
+    /// A galaxy code comes first, then the preset, then the other options.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, CliError> {
+        let mut cli = Cli {
+            help: false,
+            configuration: Configuration::default(),
+            watch: ConfigurationWatch::default(),
+            units: MapUnits::default(),
+            width: None,
+            height: None,
+            fullscreen: false,
+            hide_ui: false,
+        };
+        let mut code = None;
+        let mut preset = None;
+        let mut overrides = Vec::new();
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            // -- Both `--flag value` and `--flag=value` are accepted
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+            let mut value = || {
+                inline
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| CliError::MissingValue(flag.clone()))
+            };
+
+            match flag.as_str() {
+                "--help" | "-h" => cli.help = parse_switch(&flag, inline.as_deref())?,
+                "--fullscreen" => cli.fullscreen = parse_switch(&flag, inline.as_deref())?,
+                "--hide-ui" => cli.hide_ui = parse_switch(&flag, inline.as_deref())?,
+                "--width" => cli.width = Some(parse_size(&flag, &value()?)?),
+                "--height" => cli.height = Some(parse_size(&flag, &value()?)?),
+                "--code" => code = Some(value()?),
+                "--preset" => preset = Some(PathBuf::from(value()?)),
+                "--parsecs-per-scene-unit" => {
+                    overrides.push((flag.clone(), Some(value()?)));
+                }
+                "--dust" | "--no-dust" | "--h2" | "--no-h2" | "--filament" | "--no-filament" => {
+                    // -- `--dust=false` reads as `--no-dust`
+                    let negated = flag.starts_with("--no-");
+                    let kind = flag.trim_start_matches("--").trim_start_matches("no-");
+                    let flag = if parse_switch(&flag, inline.as_deref())? != negated {
+                        format!("--{kind}")
+                    } else {
+                        format!("--no-{kind}")
+                    };
+                    overrides.push((flag, None));
+                }
+                _ if flag == "--seed" || parameter_for_flag(&flag).is_some() => {
+                    overrides.push((flag.clone(), Some(value()?)));
+                }
+                _ => return Err(CliError::UnknownFlag(flag)),
+            }
+        }
+
+        if cli.help {
+            return Ok(cli);
+        }
+
+        // -- A code gives the whole galaxy, the preset and options then change it
+        if let Some(code) = code {
+            cli.configuration = decode_galaxy_code(&code).map_err(CliError::Code)?;
+        }
+
+        // -- Without `--preset` the default file is only read if it's there
+        let preset_given = preset.is_some();
+        if let Some(preset) = preset {
+            cli.watch.path = preset;
+        }
+        match fs::metadata(&cli.watch.path).and_then(|metadata| metadata.modified()) {
+            Ok(modified) => cli.apply_preset(modified)?,
+            Err(error) if preset_given => {
+                return Err(CliError::Preset {
+                    path: cli.watch.path,
+                    error: error.to_string(),
+                });
+            }
+            Err(_) => {}
+        }
+
+        for (flag, value) in overrides {
+            cli.apply_override(flag, value)?;
+        }
+        cli.configuration.validate().map_err(CliError::Invalid)?;
+
+        Ok(cli)
+    }
+
+    fn apply_preset(&mut self, modified: SystemTime) -> Result<(), CliError> {
+        let file = ConfigurationFile::load(&self.watch.path).map_err(|error| CliError::Preset {
+            path: self.watch.path.clone(),
+            error,
+        })?;
+
+        file.apply(&mut self.configuration);
+        self.units = file
+            .map_units(&self.units)
+            .map_err(|error| CliError::Preset {
+                path: self.watch.path.clone(),
+                error,
+            })?;
+        // -- Already applied, only later saves are picked up
+        self.watch.modified = Some(modified);
+
+        Ok(())
+    }
+
+    fn apply_override(&mut self, flag: String, value: Option<String>) -> Result<(), CliError> {
+        let config = &mut self.configuration;
+        let Some(value) = value else {
+            match flag.as_str() {
+                "--dust" => config.dust = true,
+                "--no-dust" => config.dust = false,
+                "--h2" => config.h2 = true,
+                "--no-h2" => config.h2 = false,
+                "--filament" => config.filament = true,
+                _ => config.filament = false,
+            }
+
+            return Ok(());
+        };
+
+        if flag == "--parsecs-per-scene-unit" {
+            let scale = value.parse().ok().and_then(|scale| parse_map_scale(scale).ok());
+            self.units.parsecs_per_scene_unit =
+                scale.ok_or(CliError::InvalidValue { flag, value })?;
+
+            return Ok(());
+        }
+
+        let Some(parameter) = parameter_for_flag(&flag) else {
+            config.seed = value
+                .parse()
+                .map_err(|_| CliError::InvalidValue { flag, value })?;
+
+            return Ok(());
+        };
+
+        let Some(number) = parameter.parse(&value) else {
+            return Err(CliError::InvalidValue { flag, value });
+        };
+        if !(parameter.min..=parameter.max).contains(&number) {
+            return Err(CliError::OutOfRange {
+                flag,
+                value: number,
+                min: parameter.min,
+                max: parameter.max,
+            });
+        }
+
+        parameter.apply(config, number);
+
+        Ok(())
+    }
+}
+
+/// `--star-count` for `star_count`
+fn parameter_for_flag(flag: &str) -> Option<&'static Parameter> {
+    let key = flag.strip_prefix("--")?.replace('-', "_");
+
+    PARAMETERS.iter().find(|parameter| parameter.key == key)
+}
+
+/// A switch is on when given alone, `--flag=true` and `--flag=false` set it explicitly
+fn parse_switch(flag: &str, value: Option<&str>) -> Result<bool, CliError> {
+    match value {
+        None | Some("true") => Ok(true),
+        Some("false") => Ok(false),
+        Some(value) => Err(CliError::InvalidValue {
+            flag: flag.to_string(),
+            value: value.to_string(),
+        }),
+    }
+}
+
+fn parse_size(flag: &str, value: &str) -> Result<f32, CliError> {
+    value
+        .parse::<f32>()
+        .ok()
+        .filter(|number| number.is_finite() && *number > 0.0)
+        .ok_or_else(|| CliError::InvalidValue {
+            flag: flag.to_string(),
+            value: value.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::configuration::code::encode_galaxy_code;
+
+    fn parse(args: &[&str]) -> Result<Cli, CliError> {
+        Cli::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    /// A preset file of its own for each test, they run in parallel
+    fn preset(name: &str, text: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("spiral-galaxy-map-{name}.ron"));
+        fs::write(&path, text).unwrap();
+
+        path
+    }
+
+    #[test]
+    fn both_forms_of_value() {
+        let separate = parse(&["--velocity", "1.5"]).unwrap();
+        let inline = parse(&["--velocity=1.5"]).unwrap();
+
+        assert_eq!(separate.configuration.velocity, 1.5);
+        assert_eq!(inline.configuration.velocity, 1.5);
+    }
+
+    #[test]
+    fn values_outside_the_limits() {
+        for parameter in PARAMETERS {
+            let flag = format!("--{}", parameter.key.replace('_', "-"));
+
+            for value in [parameter.min - 1.0, parameter.max + 1.0] {
+                let result = parse(&[&flag, &value.to_string()]);
+                assert!(
+                    matches!(result, Err(CliError::OutOfRange { .. })),
+                    "{flag} {value}"
+                );
+            }
+            assert!(parse(&[&flag, &parameter.min.to_string()]).is_ok(), "{flag}");
+            assert!(parse(&[&flag, &parameter.max.to_string()]).is_ok(), "{flag}");
+        }
+    }
+
+    #[test]
+    fn code_then_preset_then_flags() {
+        let code = encode_galaxy_code(&Configuration {
+            star_count: 6_000,
+            velocity: 0.5,
+            seed: 42,
+            ..Configuration::default()
+        });
+        let path = preset("precedence", "(star_count: 7000, velocity: 1.0)");
+
+        let cli = parse(&[
+            "--velocity",
+            "1.5",
+            "--code",
+            &code,
+            "--preset",
+            path.to_str().unwrap(),
+        ])
+        .unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(cli.configuration.seed, 42);
+        assert_eq!(cli.configuration.star_count, 7_000);
+        assert_eq!(cli.configuration.velocity, 1.5);
+    }
+
+    #[test]
+    fn preset_outside_the_limits() {
+        let path = preset("limits", "(star_count: 50000)");
+        let result = parse(&["--preset", path.to_str().unwrap()]);
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(result, Err(CliError::Invalid(_))));
+    }
+
+    #[test]
+    fn map_scale() {
+        let path = preset("scale", "(parsecs_per_scene_unit: 500.0)");
+        let path = path.to_str().unwrap();
+        let from_preset = parse(&["--preset", path]);
+        let from_flag = parse(&["--preset", path, "--parsecs-per-scene-unit=900"]);
+        fs::remove_file(path).unwrap();
+
+        assert_eq!(from_preset.unwrap().units.parsecs_per_scene_unit, 500.0);
+        assert_eq!(from_flag.unwrap().units.parsecs_per_scene_unit, 900.0);
+        assert!(matches!(
+            parse(&["--parsecs-per-scene-unit", "0"]),
+            Err(CliError::InvalidValue { .. })
+        ));
+    }
+
+    #[test]
+    fn switches() {
+        let cli = parse(&["--no-dust", "--h2=false", "--filament=true", "--hide-ui"]).unwrap();
+
+        assert!(!cli.configuration.dust);
+        assert!(!cli.configuration.h2);
+        assert!(cli.configuration.filament);
+        assert!(cli.hide_ui);
+
+        assert!(!parse(&["--dust=false"]).unwrap().configuration.dust);
+        assert!(parse(&["--no-dust=false"]).unwrap().configuration.dust);
+        assert!(!parse(&["--fullscreen=false"]).unwrap().fullscreen);
+        assert!(matches!(
+            parse(&["--dust=off"]),
+            Err(CliError::InvalidValue { .. })
+        ));
+    }
+
+    #[test]
+    fn malformed_options() {
+        assert!(matches!(
+            parse(&["--velocity"]),
+            Err(CliError::MissingValue(flag)) if flag == "--velocity"
+        ));
+        assert!(matches!(
+            parse(&["--speed", "1"]),
+            Err(CliError::UnknownFlag(flag)) if flag == "--speed"
+        ));
+        assert!(matches!(
+            parse(&["--velocity", "fast"]),
+            Err(CliError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            parse(&["--width", "-1"]),
+            Err(CliError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            parse(&["--preset", "missing.ron"]),
+            Err(CliError::Preset { .. })
+        ));
+    }
+}
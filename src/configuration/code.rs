@@ -0,0 +1,213 @@
+use std::fmt;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+
+use crate::configuration::resources::Configuration;
+use crate::configuration::validation::InvalidConfiguration;
+use crate::scene::SemiAxis;
+
+/// Version of the codes written, bumped whenever their layout changes.
+/// Decoding matches each known version to its own reader instead,
+/// so that older codes keep decoding.
+const CODE_VERSION: u8 = 1;
+
+#[derive(Debug)]
+pub enum CodeError {
+    NotBase64,
+    UnknownVersion(u8),
+    Truncated,
+    Invalid(InvalidConfiguration),
+}
+
+impl fmt::Display for CodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CodeError::NotBase64 => write!(f, "not a galaxy code"),
+            CodeError::UnknownVersion(version) => {
+                write!(f, "made by a newer version (v{version})")
+            }
+            CodeError::Truncated => write!(f, "the code is incomplete"),
+            CodeError::Invalid(errors) => write!(f, "{errors}"),
+        }
+    }
+}
+
+/// Short string holding a whole configuration, seed included
+pub fn encode_galaxy_code(config: &Configuration) -> String {
+    let flags = config.dust as u8 | (config.h2 as u8) << 1 | (config.filament as u8) << 2;
+
+    let mut bytes = vec![CODE_VERSION, flags, config.stars_per_orbit];
+    bytes.extend(config.star_count.to_le_bytes());
+    for value in [
+        config.velocity,
+        config.semi_axis.major,
+        config.semi_axis.minor,
+        config.dimming_speed,
+        config.orbit_density,
+        config.orbit_rotation,
+        config.star_size,
+    ] {
+        bytes.extend(value.to_le_bytes());
+    }
+    bytes.extend(config.seed.to_le_bytes());
+
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+pub fn decode_galaxy_code(code: &str) -> Result<Configuration, CodeError> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(code.trim())
+        .map_err(|_| CodeError::NotBase64)?;
+    let mut reader = CodeReader { bytes: &bytes };
+
+    // -- Every version ever written has a reader, not only the current one
+    let config = match reader.u8()? {
+        1 => read_v1(&mut reader)?,
+        version => return Err(CodeError::UnknownVersion(version)),
+    };
+
+    // -- A mistyped code is rejected rather than loading some other galaxy
+    config.validate().map_err(CodeError::Invalid)?;
+
+    Ok(config)
+}
+
+fn read_v1(reader: &mut CodeReader) -> Result<Configuration, CodeError> {
+    let flags = reader.u8()?;
+    let stars_per_orbit = reader.u8()?;
+    let star_count = u32::from_le_bytes(reader.take()?);
+    let velocity = reader.f32()?;
+    let semi_axis = SemiAxis {
+        major: reader.f32()?,
+        minor: reader.f32()?,
+    };
+
+    // -- Fields are read in the order they were written
+    Ok(Configuration {
+        dust: flags & 1 != 0,
+        h2: flags & 2 != 0,
+        filament: flags & 4 != 0,
+        star_count,
+        velocity,
+        semi_axis,
+        stars_per_orbit,
+        dimming_speed: reader.f32()?,
+        orbit_density: reader.f32()?,
+        orbit_rotation: reader.f32()?,
+        star_size: reader.f32()?,
+        seed: u64::from_le_bytes(reader.take()?),
+    })
+}
+
+struct CodeReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl CodeReader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], CodeError> {
+        if self.bytes.len() < N {
+            return Err(CodeError::Truncated);
+        }
+        let (head, rest) = self.bytes.split_at(N);
+        self.bytes = rest;
+
+        Ok(head.try_into().unwrap())
+    }
+
+    fn u8(&mut self) -> Result<u8, CodeError> {
+        self.take::<1>().map(|[byte]| byte)
+    }
+
+    fn f32(&mut self) -> Result<f32, CodeError> {
+        self.take().map(f32::from_le_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Written by version 1, must keep decoding to `v1_configuration`
+    const V1_CODE: &str = "AQZkECcAAAAAgD_NzMw-zcxMPgAAFkMAAABAzcxMPgrXozwqAAAAAAAAAA";
+
+    fn v1_configuration() -> Configuration {
+        Configuration {
+            dust: false,
+            seed: 42,
+            ..Configuration::default()
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let config = Configuration {
+            h2: false,
+            star_count: 12_345,
+            velocity: 1.7,
+            semi_axis: SemiAxis {
+                major: 0.5,
+                minor: 0.3,
+            },
+            stars_per_orbit: 120,
+            orbit_rotation: 0.35,
+            seed: u64::MAX - 7,
+            ..Configuration::default()
+        };
+
+        assert_eq!(decode_galaxy_code(&encode_galaxy_code(&config)).unwrap(), config);
+    }
+
+    #[test]
+    fn decodes_version_1() {
+        assert_eq!(decode_galaxy_code(V1_CODE).unwrap(), v1_configuration());
+        // -- Surrounding whitespace is left over from copying
+        assert_eq!(
+            decode_galaxy_code(&format!("  {}\n", V1_CODE)).unwrap(),
+            v1_configuration()
+        );
+    }
+
+    #[test]
+    fn truncated() {
+        for code in ["", &V1_CODE[..V1_CODE.len() - 4], &V1_CODE[..2]] {
+            assert!(
+                matches!(decode_galaxy_code(code), Err(CodeError::Truncated)),
+                "{:?} should be truncated",
+                code
+            );
+        }
+    }
+
+    #[test]
+    fn not_base64() {
+        assert!(matches!(
+            decode_galaxy_code("not a code!"),
+            Err(CodeError::NotBase64)
+        ));
+    }
+
+    #[test]
+    fn out_of_limits() {
+        let config = Configuration {
+            star_count: 50_000,
+            ..Configuration::default()
+        };
+
+        assert!(matches!(
+            decode_galaxy_code(&encode_galaxy_code(&config)),
+            Err(CodeError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn unknown_version() {
+        let mut bytes = URL_SAFE_NO_PAD.decode(V1_CODE).unwrap();
+        bytes[0] = CODE_VERSION + 1;
+
+        assert!(matches!(
+            decode_galaxy_code(&URL_SAFE_NO_PAD.encode(bytes)),
+            Err(CodeError::UnknownVersion(version)) if version == CODE_VERSION + 1
+        ));
+    }
+}
@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+/// Moves through the configuration history
+#[derive(Event, Clone, Copy)]
+pub enum HistoryStep {
+    Undo,
+    Redo,
+}
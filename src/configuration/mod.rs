@@ -1,13 +1,35 @@
 use bevy::prelude::*;
 
-use resources::Configuration;
+use events::HistoryStep;
+use resources::*;
+use systems::*;
 
+pub mod code;
+pub mod events;
+pub mod parameters;
 pub mod resources;
+pub mod systems;
+pub mod validation;
 
 pub struct ConfigurationPlugin;
 
 impl Plugin for ConfigurationPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<Configuration>();
+        app.init_resource::<Configuration>()
+            .init_resource::<MapUnits>()
+            .init_resource::<ConfigurationHistory>()
+            .init_resource::<ConfigurationWatch>()
+            .init_resource::<ParameterLocks>()
+            .add_event::<HistoryStep>()
+            .add_systems(
+                Update,
+                (
+                    watch_configuration_file,
+                    read_history_keys,
+                    step_history,
+                    record_history.run_if(resource_changed::<Configuration>),
+                )
+                    .chain(),
+            );
     }
 }
@@ -0,0 +1,295 @@
+use rand::Rng;
+
+use crate::configuration::resources::Configuration;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParameterCategory {
+    Bodies,
+    Orbits,
+}
+
+impl ParameterCategory {
+    pub const ALL: [ParameterCategory; 2] = [ParameterCategory::Bodies, ParameterCategory::Orbits];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ParameterCategory::Bodies => "Bodies",
+            ParameterCategory::Orbits => "Orbits",
+        }
+    }
+}
+
+/// Numeric `Configuration` field, with everything needed to show,
+/// edit and validate it
+pub struct Parameter {
+    /// Shown next to its controls
+    pub name: &'static str,
+    /// Used by presets and on the command line
+    pub key: &'static str,
+    pub category: ParameterCategory,
+    pub min: f32,
+    pub max: f32,
+    /// Slider step
+    pub step: f32,
+    /// Decimal places shown in its number field
+    pub decimals: usize,
+    /// Changes reshape the existing bodies rather than regenerating them,
+    /// and it's interpolated between timeline keyframes
+    pub continuous: bool,
+    pub get: fn(&Configuration) -> f32,
+    pub set: fn(&mut Configuration, f32),
+}
+
+impl Parameter {
+    pub fn value(&self, config: &Configuration) -> f32 {
+        (self.get)(config)
+    }
+
+    /// Sets the field, clamped to its limits
+    pub fn apply(&self, config: &mut Configuration, value: f32) {
+        (self.set)(config, value.clamp(self.min, self.max));
+    }
+
+    /// Value as shown, and typed, in its number field
+    pub fn format(&self, config: &Configuration) -> String {
+        format!("{:.*}", self.decimals, self.value(config))
+    }
+
+    /// Reads a typed value, thousands separators are allowed, e.g. "12,000"
+    pub fn parse(&self, text: &str) -> Option<f32> {
+        let text = text.replace([',', '_', ' '], "");
+
+        text.parse::<f32>().ok().filter(|value| value.is_finite())
+    }
+
+    pub fn snap(&self, value: f32) -> f32 {
+        ((value / self.step).round() * self.step).clamp(self.min, self.max)
+    }
+
+    /// Any value within the limits, on a step
+    pub fn sample(&self, rng: &mut impl Rng) -> f32 {
+        self.snap(rng.gen_range(self.min..=self.max))
+    }
+
+    /// Value around `value`, most likely close to it and
+    /// at most a quarter of the range away
+    pub fn sample_near(&self, value: f32, rng: &mut impl Rng) -> f32 {
+        let reach = (self.max - self.min) / 4.0;
+        let offset = (rng.gen::<f32>() - rng.gen::<f32>()) * reach;
+
+        self.snap(value + offset)
+    }
+}
+
+impl PartialEq for Parameter {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+/// Whether `other` can be shown by reshaping the bodies generated for `config`,
+/// that is whether they only differ in continuous parameters
+pub fn same_bodies(config: &Configuration, other: &Configuration) -> bool {
+    let mut reshaped = config.clone();
+    for parameter in PARAMETERS.iter().filter(|parameter| parameter.continuous) {
+        (parameter.set)(&mut reshaped, parameter.value(other));
+    }
+
+    reshaped == *other
+}
+
+/// Every numeric parameter, in the order they are shown
+pub const PARAMETERS: &[Parameter] = &[
+    Parameter {
+        name: "Star Count",
+        key: "star_count",
+        category: ParameterCategory::Bodies,
+        min: 5_000.0,
+        max: 15_000.0,
+        step: 1_000.0,
+        decimals: 0,
+        continuous: false,
+        get: |c| c.star_count as f32,
+        set: |c, v| c.star_count = v.round() as u32,
+    },
+    Parameter {
+        name: "Star Size",
+        key: "star_size",
+        category: ParameterCategory::Bodies,
+        min: 0.01,
+        max: 0.07,
+        step: 0.01,
+        decimals: 2,
+        continuous: false,
+        get: |c| c.star_size,
+        set: |c, v| c.star_size = v,
+    },
+    Parameter {
+        name: "Dimming Speed",
+        key: "dimming_speed",
+        category: ParameterCategory::Bodies,
+        min: 0.0,
+        max: 300.0,
+        step: 10.0,
+        decimals: 0,
+        continuous: false,
+        get: |c| c.dimming_speed,
+        set: |c, v| c.dimming_speed = v,
+    },
+    Parameter {
+        name: "Velocity",
+        key: "velocity",
+        category: ParameterCategory::Orbits,
+        min: 0.1,
+        max: 5.0,
+        step: 0.1,
+        decimals: 2,
+        continuous: true,
+        get: |c| c.velocity,
+        set: |c, v| c.velocity = v,
+    },
+    Parameter {
+        name: "Semi Axis",
+        key: "semi_axis_major",
+        category: ParameterCategory::Orbits,
+        min: 0.2,
+        max: 1.1,
+        step: 0.05,
+        decimals: 2,
+        continuous: true,
+        get: |c| c.semi_axis.major,
+        // -- The minor semi-axis keeps its proportion
+        set: |c, v| c.semi_axis = c.semi_axis.with_major(v),
+    },
+    Parameter {
+        name: "Orbit Rotation",
+        key: "orbit_rotation",
+        category: ParameterCategory::Orbits,
+        min: 0.0,
+        max: 1.0,
+        step: 0.1,
+        decimals: 2,
+        continuous: true,
+        get: |c| c.orbit_rotation,
+        set: |c, v| c.orbit_rotation = v,
+    },
+    Parameter {
+        name: "Orbit Density",
+        key: "orbit_density",
+        category: ParameterCategory::Orbits,
+        min: 0.5,
+        max: 4.0,
+        step: 0.1,
+        decimals: 2,
+        continuous: true,
+        get: |c| c.orbit_density,
+        set: |c, v| c.orbit_density = v,
+    },
+    Parameter {
+        name: "Stars per Orbit",
+        key: "stars_per_orbit",
+        category: ParameterCategory::Orbits,
+        min: 20.0,
+        max: 250.0,
+        step: 10.0,
+        decimals: 0,
+        continuous: false,
+        get: |c| c.stars_per_orbit as f32,
+        set: |c, v| c.stars_per_orbit = v.round() as u8,
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::scene::SemiAxis;
+
+    /// Whether `value` is a whole number of steps, or one of the limits it was clamped to
+    fn on_step(parameter: &Parameter, value: f32) -> bool {
+        let steps = value / parameter.step;
+
+        (steps - steps.round()).abs() < 1e-3 || value == parameter.min || value == parameter.max
+    }
+
+    #[test]
+    fn samples_stay_within_limits_on_a_step() {
+        let mut rng = StdRng::seed_from_u64(7);
+
+        for parameter in PARAMETERS {
+            for _ in 0..50 {
+                let value = parameter.sample(&mut rng);
+
+                assert!((parameter.min..=parameter.max).contains(&value), "{}", parameter.key);
+                assert!(on_step(parameter, value), "{} {value}", parameter.key);
+            }
+        }
+    }
+
+    #[test]
+    fn nudges_stay_close() {
+        let mut rng = StdRng::seed_from_u64(7);
+
+        for parameter in PARAMETERS {
+            let reach = (parameter.max - parameter.min) / 4.0;
+
+            for value in [parameter.min, (parameter.min + parameter.max) / 2.0, parameter.max] {
+                for _ in 0..50 {
+                    let nudged = parameter.sample_near(value, &mut rng);
+
+                    assert!((parameter.min..=parameter.max).contains(&nudged));
+                    // -- Half a step of slack for the snapping
+                    assert!((nudged - value).abs() <= reach + parameter.step / 2.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn continuous_changes_keep_the_bodies() {
+        let config = Configuration::default();
+        let other = Configuration {
+            velocity: config.velocity * 2.0,
+            semi_axis: config.semi_axis.with_major(0.8),
+            orbit_rotation: 0.5,
+            orbit_density: config.orbit_density + 0.1,
+            ..config.clone()
+        };
+
+        assert!(same_bodies(&config, &config));
+        assert!(same_bodies(&config, &other));
+    }
+
+    #[test]
+    fn other_changes_regenerate_them() {
+        let config = Configuration::default();
+        let changes = [
+            Configuration {
+                star_count: config.star_count + 1_000,
+                ..config.clone()
+            },
+            Configuration {
+                seed: config.seed + 1,
+                ..config.clone()
+            },
+            Configuration {
+                dust: !config.dust,
+                ..config.clone()
+            },
+            // -- Only the major semi-axis is continuous, the minor one keeps its proportion
+            Configuration {
+                semi_axis: SemiAxis {
+                    major: config.semi_axis.major,
+                    minor: config.semi_axis.minor / 2.0,
+                },
+                ..config.clone()
+            },
+        ];
+
+        for other in changes {
+            assert!(!same_bodies(&config, &other), "{other:?}");
+        }
+    }
+}
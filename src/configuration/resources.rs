@@ -1,9 +1,17 @@
+use std::collections::{HashSet, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
 use bevy::prelude::*;
+use rand::prelude::*;
+use ron::extensions::Extensions;
+use serde::Deserialize;
 
+use crate::configuration::parameters::Parameter;
 use crate::scene::SemiAxis;
-use crate::ui::components::{ButtonAction, ButtonKind};
 
-#[derive(Resource, Clone)]
+#[derive(Resource, Debug, Clone, PartialEq)]
 pub struct Configuration {
     pub dust: bool,
     pub h2: bool,
@@ -16,148 +24,400 @@ pub struct Configuration {
     pub orbit_density: f32,
     pub orbit_rotation: f32,
     pub star_size: f32,
+    /// Seed of the random spread of orbits, the same seed gives the same galaxy
+    pub seed: u64,
 }
 
 impl Configuration {
-    pub fn map_to_button_value(&self, button_kind: ButtonKind) -> String {
-        match button_kind {
-            ButtonKind::StarCount => format!("{}.000", self.star_count / 1_000),
-            ButtonKind::StarSize => format!("{:.2}", self.star_size),
-            ButtonKind::Velocity => format!("{:.2}", self.velocity),
-            ButtonKind::SemiAxis => format!("{}", self.semi_axis),
-            ButtonKind::OrbitDensity => format!("{:.2}", self.orbit_density),
-            ButtonKind::OrbitRotation => format!("{:.2}", self.orbit_rotation),
+    /// Orbits the stars fill, none when an orbit holds no stars
+    pub fn orbit_count(&self) -> u32 {
+        self.star_count
+            .checked_div(self.stars_per_orbit as u32)
+            .unwrap_or(0)
+    }
+
+    /// Semi-major axis of the outermost orbit, including the random spread
+    pub fn galaxy_radius(&self) -> f32 {
+        let last_orbit = self.orbit_count().saturating_sub(1) as f32;
+
+        self.semi_axis.major * (1.0 + last_orbit / self.orbit_density) * 1.1
+    }
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Self {
+            velocity: 1.0,
+            star_count: 10_000,
+            dust: true,
+            h2: true,
+            filament: true,
+            semi_axis: SemiAxis::default(),
+            stars_per_orbit: 100,
+            dimming_speed: 150.0,
+            orbit_density: 2.0,
+            orbit_rotation: 0.2,
+            star_size: 0.02,
+            seed: thread_rng().gen(),
         }
     }
+}
 
-    pub fn update(&mut self, button_kind: ButtonKind, action: ButtonAction) {
-        match button_kind {
-            ButtonKind::StarCount => {
-                self.update_star_count(action);
-            }
-            ButtonKind::StarSize => {
-                self.update_star_size(action);
-            }
-            ButtonKind::Velocity => {
-                self.update_velocity(action);
-            }
-            ButtonKind::SemiAxis => {
-                self.update_semi_axis(action);
-            }
-            ButtonKind::OrbitDensity => {
-                self.update_orbit_density(action);
-            }
-            ButtonKind::OrbitRotation => {
-                self.update_orbit_rotation(action);
-            }
+/// Configuration as written in the watched file, fields left out keep their value
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigurationFile {
+    pub dust: Option<bool>,
+    pub h2: Option<bool>,
+    pub filament: Option<bool>,
+    pub star_count: Option<u32>,
+    pub velocity: Option<f32>,
+    /// The minor semi-axis keeps its proportion
+    pub semi_axis_major: Option<f32>,
+    pub stars_per_orbit: Option<u8>,
+    pub dimming_speed: Option<f32>,
+    pub orbit_density: Option<f32>,
+    pub orbit_rotation: Option<f32>,
+    pub star_size: Option<f32>,
+    pub seed: Option<u64>,
+    /// Map scale, applied to `MapUnits` so that it doesn't regenerate the galaxy
+    pub parsecs_per_scene_unit: Option<f32>,
+}
+
+impl ConfigurationFile {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path).map_err(|error| error.to_string())?;
+
+        // -- Lets the file say `velocity: 1.5` rather than `velocity: Some(1.5)`
+        ron::Options::default()
+            .with_default_extension(Extensions::IMPLICIT_SOME)
+            .from_str(&text)
+            .map_err(|error| error.to_string())
+    }
+
+    pub fn apply(&self, config: &mut Configuration) {
+        config.dust = self.dust.unwrap_or(config.dust);
+        config.h2 = self.h2.unwrap_or(config.h2);
+        config.filament = self.filament.unwrap_or(config.filament);
+        config.star_count = self.star_count.unwrap_or(config.star_count);
+        config.velocity = self.velocity.unwrap_or(config.velocity);
+        if let Some(major) = self.semi_axis_major {
+            config.semi_axis = config.semi_axis.with_major(major);
         }
+        config.stars_per_orbit = self.stars_per_orbit.unwrap_or(config.stars_per_orbit);
+        config.dimming_speed = self.dimming_speed.unwrap_or(config.dimming_speed);
+        config.orbit_density = self.orbit_density.unwrap_or(config.orbit_density);
+        config.orbit_rotation = self.orbit_rotation.unwrap_or(config.orbit_rotation);
+        config.star_size = self.star_size.unwrap_or(config.star_size);
+        config.seed = self.seed.unwrap_or(config.seed);
     }
 
-    const STAR_COUNT_STEP: u32 = 1_000;
-    const STAR_COUNT_LIMIT: (u32, u32) = (5_000, 15_000);
+    /// `units` with the file's map scale, if it gives a usable one
+    pub fn map_units(&self, units: &MapUnits) -> Result<MapUnits, String> {
+        let mut units = *units;
 
-    fn update_star_count(&mut self, action: ButtonAction) {
-        match action {
-            ButtonAction::Increment if self.star_count < Self::STAR_COUNT_LIMIT.1 => {
-                self.star_count += Self::STAR_COUNT_STEP;
-            }
-            ButtonAction::Decrement if self.star_count > Self::STAR_COUNT_LIMIT.0 => {
-                self.star_count -= Self::STAR_COUNT_STEP;
-            }
-            _ => {}
+        if let Some(scale) = self.parsecs_per_scene_unit {
+            units.parsecs_per_scene_unit = parse_map_scale(scale)?;
         }
+
+        Ok(units)
     }
+}
 
-    const STAR_SIZE_STEP: f32 = 0.01;
-    const STAR_SIZE_LIMIT: (f32, f32) = (0.01, 0.07);
+/// Configuration file applied whenever it's saved
+#[derive(Resource)]
+pub struct ConfigurationWatch {
+    pub path: PathBuf,
+    /// Modification time of the file when it was last read
+    pub modified: Option<SystemTime>,
+    /// How often the file is checked for changes
+    pub poll: Timer,
+}
 
-    fn update_star_size(&mut self, action: ButtonAction) {
-        match action {
-            ButtonAction::Increment if self.star_size < Self::STAR_SIZE_LIMIT.1 => {
-                self.star_size += Self::STAR_SIZE_STEP;
-            }
-            ButtonAction::Decrement if self.star_size > Self::STAR_SIZE_LIMIT.0 => {
-                self.star_size -= Self::STAR_SIZE_STEP;
-            }
-            _ => {}
+impl Default for ConfigurationWatch {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::from("galaxy.ron"),
+            modified: None,
+            poll: Timer::from_seconds(0.5, TimerMode::Repeating),
         }
     }
+}
 
-    const VELOCITY_STEP: f32 = 0.1;
-    const VELOCITY_LIMIT: (f32, f32) = (0.1, 5.0);
+/// Parameters left as they are when the configuration is randomized
+#[derive(Resource, Default)]
+pub struct ParameterLocks {
+    /// Keys of the locked parameters
+    pub locked: HashSet<&'static str>,
+}
 
-    fn update_velocity(&mut self, action: ButtonAction) {
-        match action {
-            ButtonAction::Increment if self.velocity < Self::VELOCITY_LIMIT.1 => {
-                self.velocity += Self::VELOCITY_STEP
-            }
-            ButtonAction::Decrement if self.velocity > Self::VELOCITY_LIMIT.0 => {
-                self.velocity -= Self::VELOCITY_STEP
-            }
-            _ => {},
+impl ParameterLocks {
+    pub fn is_locked(&self, parameter: &Parameter) -> bool {
+        self.locked.contains(parameter.key)
+    }
+
+    /// Returns whether the parameter is now locked
+    pub fn toggle(&mut self, parameter: &Parameter) -> bool {
+        if !self.locked.remove(parameter.key) {
+            self.locked.insert(parameter.key);
+        }
+
+        self.is_locked(parameter)
+    }
+}
+
+/// Configurations that were applied before and after the current one
+#[derive(Resource, Default)]
+pub struct ConfigurationHistory {
+    /// Oldest first, so that the oldest can be dropped cheaply
+    pub undo: VecDeque<Configuration>,
+    pub redo: Vec<Configuration>,
+    /// Last recorded configuration, `None` until the first one is recorded
+    pub current: Option<Configuration>,
+}
+
+impl ConfigurationHistory {
+    /// Oldest snapshots are dropped past this many
+    pub const LIMIT: usize = 100;
+
+    /// Keeps `config` as the current configuration, the previous one can be undone
+    pub fn record(&mut self, config: &Configuration) {
+        let Some(current) = self.current.replace(config.clone()) else {
+            return;
         };
+        if current == *config {
+            return;
+        }
+
+        self.undo.push_back(current);
+        if self.undo.len() > Self::LIMIT {
+            self.undo.pop_front();
+        }
+        self.redo.clear();
     }
 
-    const SEMI_AXIS_STEP: f32 = 0.05;
-    const SEMI_AXIS_LIMIT: (f32, f32) = (0.2, 1.1);
+    pub fn undo(&mut self) -> Option<Configuration> {
+        let previous = self.undo.pop_back()?;
+        if let Some(current) = self.current.replace(previous.clone()) {
+            self.redo.push(current);
+        }
 
-    fn update_semi_axis(&mut self, action: ButtonAction) {
-        match action {
-            ButtonAction::Increment if self.semi_axis.major < Self::SEMI_AXIS_LIMIT.1 => {
-                self.semi_axis += Self::SEMI_AXIS_STEP;
-            }
-            ButtonAction::Decrement if self.semi_axis.major > Self::SEMI_AXIS_LIMIT.0 => {
-                self.semi_axis -= Self::SEMI_AXIS_STEP;
-            }
-            _ => {}
+        Some(previous)
+    }
+
+    pub fn redo(&mut self) -> Option<Configuration> {
+        let next = self.redo.pop()?;
+        if let Some(current) = self.current.replace(next.clone()) {
+            self.undo.push_back(current);
         }
+
+        Some(next)
     }
+}
 
-    const ORBIT_DENSITY_STEP: f32 = 0.1;
-    const ORBIT_DENSITY_LIMIT: (f32, f32) = (0.5, 4.0);
+pub const LIGHT_YEARS_PER_PARSEC: f32 = 3.2616;
 
-    fn update_orbit_density(&mut self, action: ButtonAction) {
-        match action {
-            ButtonAction::Increment if self.orbit_density < Self::ORBIT_DENSITY_LIMIT.1 => {
-                self.orbit_density += Self::ORBIT_DENSITY_STEP;
-            }
-            ButtonAction::Decrement if self.orbit_density > Self::ORBIT_DENSITY_LIMIT.0 => {
-                self.orbit_density -= Self::ORBIT_DENSITY_STEP;
-            }
-            _ => {}
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DistanceUnit {
+    Scene,
+    Parsec,
+    LightYear,
+}
+
+impl DistanceUnit {
+    pub fn next(self) -> Self {
+        match self {
+            DistanceUnit::Scene => DistanceUnit::Parsec,
+            DistanceUnit::Parsec => DistanceUnit::LightYear,
+            DistanceUnit::LightYear => DistanceUnit::Scene,
         }
     }
 
-    const ORBIT_ROTATION_STEP: f32 = 0.1;
-    const ORBIT_ROTATION_LIMIT: (f32, f32) = (0.0, 1.0);
+    pub fn symbol(self) -> &'static str {
+        match self {
+            DistanceUnit::Scene => "u",
+            DistanceUnit::Parsec => "pc",
+            DistanceUnit::LightYear => "ly",
+        }
+    }
+}
 
-    fn update_orbit_rotation(&mut self, action: ButtonAction) {
-        match action {
-            ButtonAction::Increment if self.orbit_rotation < Self::ORBIT_ROTATION_LIMIT.1 => {
-                self.orbit_rotation += Self::ORBIT_ROTATION_STEP;
-            }
-            ButtonAction::Decrement if self.orbit_rotation > Self::ORBIT_ROTATION_LIMIT.0 => {
-                self.orbit_rotation -= Self::ORBIT_ROTATION_STEP;
+/// How scene distances read on the map. Kept apart from
+/// `Configuration` so that switching units doesn't regenerate bodies.
+#[derive(Resource, Clone, Copy, PartialEq, Debug)]
+pub struct MapUnits {
+    pub unit: DistanceUnit,
+    /// Parsecs covered by one scene unit
+    pub parsecs_per_scene_unit: f32,
+}
+
+impl MapUnits {
+    pub fn convert(&self, scene_distance: f32) -> f32 {
+        match self.unit {
+            DistanceUnit::Scene => scene_distance,
+            DistanceUnit::Parsec => scene_distance * self.parsecs_per_scene_unit,
+            DistanceUnit::LightYear => {
+                scene_distance * self.parsecs_per_scene_unit * LIGHT_YEARS_PER_PARSEC
             }
-            _ => {}
         }
     }
+
+    pub fn format(&self, scene_distance: f32) -> String {
+        format!("{:.2} {}", self.convert(scene_distance), self.unit.symbol())
+    }
 }
 
-impl Default for Configuration {
+/// A map scale is any positive number of parsecs per scene unit
+pub fn parse_map_scale(scale: f32) -> Result<f32, String> {
+    if scale.is_finite() && scale > 0.0 {
+        Ok(scale)
+    } else {
+        Err(format!("the map scale is {scale}, it must be above 0"))
+    }
+}
+
+impl Default for MapUnits {
     fn default() -> Self {
         Self {
-            velocity: 1.0,
-            star_count: 10_000,
-            dust: true,
-            h2: true,
-            filament: true,
-            semi_axis: SemiAxis::default(),
-            stars_per_orbit: 100,
-            dimming_speed: 150.0,
-            orbit_density: 2.0,
-            orbit_rotation: 0.2,
-            star_size: 0.02,
+            unit: DistanceUnit::Parsec,
+            // -- Spreads the default galaxy over roughly the Milky Way's 15 kpc radius
+            parsecs_per_scene_unit: 700.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configuration::parameters::PARAMETERS;
+    use crate::configuration::validation::ConfigurationError;
+
+    fn config(seed: u64) -> Configuration {
+        Configuration {
+            seed,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn no_orbits_without_stars_per_orbit() {
+        let config = Configuration {
+            stars_per_orbit: 0,
+            ..default()
+        };
+
+        assert_eq!(config.orbit_count(), 0);
+        assert!(config.galaxy_radius().is_finite());
+        assert_eq!(Configuration::default().orbit_count(), 10_000 / 100);
+    }
+
+    #[test]
+    fn map_scale_from_file() {
+        let units = MapUnits::default();
+        let file = |scale| ConfigurationFile {
+            parsecs_per_scene_unit: Some(scale),
+            ..default()
+        };
+
+        let scaled = file(1000.0).map_units(&units).unwrap();
+        assert_eq!(scaled.parsecs_per_scene_unit, 1000.0);
+        assert_eq!(scaled.unit, units.unit);
+
+        assert!(file(0.0).map_units(&units).is_err());
+        assert!(file(f32::NAN).map_units(&units).is_err());
+        assert_eq!(ConfigurationFile::default().map_units(&units), Ok(units));
+    }
+
+    #[test]
+    fn preset_major_semi_axis_out_of_range() {
+        let file = |major| ConfigurationFile {
+            semi_axis_major: Some(major),
+            ..default()
+        };
+        let mut config = Configuration::default();
+
+        file(0.0).apply(&mut config);
+        assert!(matches!(
+            config.validate().unwrap_err().0[..],
+            [ConfigurationError::OutOfRange {
+                parameter: "Semi Axis",
+                value,
+                ..
+            }] if value == 0.0
+        ));
+
+        // -- A valid major semi-axis afterwards gives back a valid shape
+        file(0.6).apply(&mut config);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn locks_toggle() {
+        let mut locks = ParameterLocks::default();
+        let (locked, other) = (&PARAMETERS[3], &PARAMETERS[0]);
+
+        assert!(locks.toggle(locked));
+        assert!(locks.is_locked(locked));
+        assert!(!locks.is_locked(other));
+        assert!(!locks.toggle(locked));
+        assert!(!locks.is_locked(locked));
+    }
+
+    #[test]
+    fn undo_and_redo_step_through_records() {
+        let mut history = ConfigurationHistory::default();
+        for seed in 0..3 {
+            history.record(&config(seed));
+        }
+
+        assert_eq!(history.undo(), Some(config(1)));
+        assert_eq!(history.undo(), Some(config(0)));
+        assert_eq!(history.undo(), None);
+        assert_eq!(history.redo(), Some(config(1)));
+        assert_eq!(history.redo(), Some(config(2)));
+        assert_eq!(history.redo(), None);
+    }
+
+    #[test]
+    fn new_record_clears_redo() {
+        let mut history = ConfigurationHistory::default();
+        history.record(&config(0));
+        history.record(&config(1));
+        history.undo();
+
+        history.record(&config(2));
+
+        assert_eq!(history.redo(), None);
+        assert_eq!(history.undo(), Some(config(0)));
+    }
+
+    #[test]
+    fn restored_configuration_isnt_recorded_again() {
+        let mut history = ConfigurationHistory::default();
+        history.record(&config(0));
+        history.record(&config(1));
+
+        // -- Applying the undone configuration records it, as the app does
+        let restored = history.undo().unwrap();
+        history.record(&restored);
+
+        assert!(history.undo.is_empty());
+        assert_eq!(history.redo(), Some(config(1)));
+    }
+
+    #[test]
+    fn oldest_records_are_dropped_past_the_limit() {
+        let mut history = ConfigurationHistory::default();
+        let recorded = ConfigurationHistory::LIMIT as u64 + 10;
+        for seed in 0..recorded {
+            history.record(&config(seed));
+        }
+
+        assert_eq!(history.undo.len(), ConfigurationHistory::LIMIT);
+        let mut oldest = None;
+        while let Some(previous) = history.undo() {
+            oldest = Some(previous);
         }
+        assert_eq!(oldest, Some(config(recorded - 1 - ConfigurationHistory::LIMIT as u64)));
     }
 }
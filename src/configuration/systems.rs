@@ -0,0 +1,103 @@
+use std::fs;
+
+use bevy::prelude::*;
+
+use crate::configuration::events::*;
+use crate::configuration::resources::*;
+use crate::ui::events::ShowNotice;
+
+const HISTORY_KEY: KeyCode = KeyCode::KeyZ;
+
+pub fn record_history(config: Res<Configuration>, mut history: ResMut<ConfigurationHistory>) {
+    history.record(&config);
+}
+
+/// Ctrl+Z undoes, Ctrl+Shift+Z redoes
+pub fn read_history_keys(kbd: Res<ButtonInput<KeyCode>>, mut evw_step: EventWriter<HistoryStep>) {
+    let ctrl = kbd.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    let shift = kbd.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    if ctrl && kbd.just_pressed(HISTORY_KEY) {
+        evw_step.send(if shift { HistoryStep::Redo } else { HistoryStep::Undo });
+    }
+}
+
+pub fn step_history(
+    mut evr_step: EventReader<HistoryStep>,
+    mut history: ResMut<ConfigurationHistory>,
+    mut config: ResMut<Configuration>,
+) {
+    for step in evr_step.read() {
+        let restored = match step {
+            HistoryStep::Undo => history.undo(),
+            HistoryStep::Redo => history.redo(),
+        };
+
+        // -- Already recorded, `record_history` leaves it alone
+        if let Some(restored) = restored {
+            *config = restored;
+        }
+    }
+}
+
+/// Applies the watched file once it's saved, `respawn_bodies` and `rebuild_ui` follow
+pub fn watch_configuration_file(
+    time: Res<Time>,
+    mut watch: ResMut<ConfigurationWatch>,
+    mut config: ResMut<Configuration>,
+    mut units: ResMut<MapUnits>,
+    mut evw_notice: EventWriter<ShowNotice>,
+) {
+    // -- Ticking alone shouldn't count as a change
+    let watch = watch.bypass_change_detection();
+    if !watch.poll.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    // -- A missing file is fine, there's just nothing to apply
+    let Ok(modified) = fs::metadata(&watch.path).and_then(|metadata| metadata.modified()) else {
+        return;
+    };
+    if watch.modified == Some(modified) {
+        return;
+    }
+    watch.modified = Some(modified);
+
+    let loaded = ConfigurationFile::load(&watch.path);
+
+    match loaded {
+        Ok(file) => {
+            let mut updated = config.clone();
+            file.apply(&mut updated);
+
+            let updated_units = updated
+                .validate()
+                .map_err(|errors| errors.to_string())
+                .and_then(|()| file.map_units(&units));
+
+            match updated_units {
+                Err(errors) => {
+                    error!("Invalid configuration in {}: {}", watch.path.display(), errors);
+                    evw_notice.send(ShowNotice {
+                        message: format!("Couldn't apply {}: {}", watch.path.display(), errors),
+                    });
+                }
+                Ok(updated_units) if updated != *config || updated_units != *units => {
+                    // -- Only what changed, a new scale alone doesn't regenerate the galaxy
+                    config.set_if_neq(updated);
+                    units.set_if_neq(updated_units);
+                    evw_notice.send(ShowNotice {
+                        message: format!("Applied {}", watch.path.display()),
+                    });
+                }
+                Ok(_) => {}
+            }
+        }
+        Err(error) => {
+            error!("Failed to load {}: {}", watch.path.display(), error);
+            evw_notice.send(ShowNotice {
+                message: format!("Couldn't apply {}: {}", watch.path.display(), error),
+            });
+        }
+    }
+}
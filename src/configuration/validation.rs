@@ -0,0 +1,210 @@
+use std::fmt;
+
+use crate::configuration::parameters::PARAMETERS;
+use crate::configuration::resources::Configuration;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigurationError {
+    NotFinite {
+        parameter: &'static str,
+    },
+    OutOfRange {
+        parameter: &'static str,
+        value: f32,
+        min: f32,
+        max: f32,
+    },
+    /// Fewer stars than fit on one orbit leave the galaxy empty
+    NoOrbits {
+        star_count: u32,
+        stars_per_orbit: u8,
+    },
+    /// The minor semi-axis must be positive and no longer than the major one
+    SemiAxisMinor {
+        minor: f32,
+        major: f32,
+    },
+}
+
+impl fmt::Display for ConfigurationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigurationError::NotFinite { parameter } => {
+                write!(f, "{parameter} isn't a number")
+            }
+            ConfigurationError::OutOfRange {
+                parameter,
+                value,
+                min,
+                max,
+            } => write!(f, "{parameter} is {value}, it must be between {min} and {max}"),
+            ConfigurationError::NoOrbits {
+                star_count,
+                stars_per_orbit,
+            } => write!(
+                f,
+                "{star_count} stars don't fill a single orbit of {stars_per_orbit}"
+            ),
+            ConfigurationError::SemiAxisMinor { minor, major } => write!(
+                f,
+                "the minor semi-axis is {minor}, it must be above 0 and at most {major}"
+            ),
+        }
+    }
+}
+
+/// Everything wrong with a configuration
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidConfiguration(pub Vec<ConfigurationError>);
+
+impl fmt::Display for InvalidConfiguration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, error) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{error}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Configuration {
+    /// Checks that bodies can be generated from this configuration
+    pub fn validate(&self) -> Result<(), InvalidConfiguration> {
+        let mut errors = Vec::new();
+
+        for parameter in PARAMETERS {
+            let value = parameter.value(self);
+
+            if !value.is_finite() {
+                errors.push(ConfigurationError::NotFinite {
+                    parameter: parameter.name,
+                });
+            } else if !(parameter.min..=parameter.max).contains(&value) {
+                errors.push(ConfigurationError::OutOfRange {
+                    parameter: parameter.name,
+                    value,
+                    min: parameter.min,
+                    max: parameter.max,
+                });
+            }
+        }
+
+        if self.star_count < self.stars_per_orbit as u32 {
+            errors.push(ConfigurationError::NoOrbits {
+                star_count: self.star_count,
+                stars_per_orbit: self.stars_per_orbit,
+            });
+        }
+
+        // -- A major semi-axis out of range is already reported, the minor one follows it
+        let semi_axis = self.semi_axis;
+        let minor_valid = semi_axis.minor > 0.0 && semi_axis.minor <= semi_axis.major;
+        if semi_axis.major > 0.0 && !minor_valid {
+            errors.push(ConfigurationError::SemiAxisMinor {
+                minor: semi_axis.minor,
+                major: semi_axis.major,
+            });
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(InvalidConfiguration(errors))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scene::SemiAxis;
+
+    fn errors(config: &Configuration) -> Vec<ConfigurationError> {
+        config.validate().map_or_else(|invalid| invalid.0, |()| Vec::new())
+    }
+
+    #[test]
+    fn default_is_valid() {
+        assert!(Configuration::default().validate().is_ok());
+    }
+
+    #[test]
+    fn not_a_number() {
+        let config = Configuration {
+            velocity: f32::NAN,
+            ..Configuration::default()
+        };
+
+        assert_eq!(
+            errors(&config),
+            [ConfigurationError::NotFinite {
+                parameter: "Velocity"
+            }]
+        );
+    }
+
+    #[test]
+    fn out_of_range() {
+        let config = Configuration {
+            star_size: 10.0,
+            ..Configuration::default()
+        };
+
+        assert!(matches!(
+            errors(&config)[..],
+            [ConfigurationError::OutOfRange {
+                parameter: "Star Size",
+                value,
+                ..
+            }] if value == 10.0
+        ));
+    }
+
+    #[test]
+    fn minor_semi_axis() {
+        for minor in [0.0, -0.1, 0.5, f32::NAN] {
+            let config = Configuration {
+                semi_axis: SemiAxis { major: 0.4, minor },
+                ..Configuration::default()
+            };
+
+            assert!(
+                matches!(errors(&config)[..], [ConfigurationError::SemiAxisMinor { .. }]),
+                "{minor}"
+            );
+        }
+    }
+
+    #[test]
+    fn every_error_is_reported() {
+        let config = Configuration {
+            star_count: 10,
+            velocity: f32::INFINITY,
+            semi_axis: SemiAxis {
+                major: 0.4,
+                minor: 0.0,
+            },
+            ..Configuration::default()
+        };
+
+        let errors = errors(&config);
+        assert_eq!(errors.len(), 4);
+        assert!(matches!(
+            errors[..],
+            [
+                ConfigurationError::OutOfRange {
+                    parameter: "Star Count",
+                    ..
+                },
+                ConfigurationError::NotFinite {
+                    parameter: "Velocity"
+                },
+                ConfigurationError::NoOrbits { star_count: 10, .. },
+                ConfigurationError::SemiAxisMinor { .. },
+            ]
+        ));
+    }
+}
@@ -1,16 +1,56 @@
 use bevy::prelude::*;
+use bevy::window::WindowMode;
 
+use cli::{Cli, USAGE};
 use configuration::ConfigurationPlugin;
 use scene::ScenePlugin;
-use ui::UIPlugin;
+use ui::{UIPlugin, UIState};
 
+mod cli;
 mod configuration;
 mod scene;
 mod ui;
 
 fn main() {
+    let cli = match Cli::parse(std::env::args().skip(1)) {
+        Ok(cli) => cli,
+        Err(err) => {
+            eprintln!("{err}\n\n{USAGE}");
+            std::process::exit(2);
+        }
+    };
+    if cli.help {
+        println!("{USAGE}");
+        return;
+    }
+
+    let mut window = Window::default();
+    window.resolution.set(
+        cli.width.unwrap_or(window.resolution.width()),
+        cli.height.unwrap_or(window.resolution.height()),
+    );
+    if cli.fullscreen {
+        window.mode = WindowMode::BorderlessFullscreen;
+    }
+
     let mut app = App::new();
 
-    app.add_plugins((DefaultPlugins, ScenePlugin, UIPlugin, ConfigurationPlugin))
-        .run();
+    app.add_plugins((
+        DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(window),
+            ..default()
+        }),
+        ScenePlugin,
+        UIPlugin,
+        ConfigurationPlugin,
+    ))
+    .insert_resource(cli.configuration)
+    .insert_resource(cli.watch)
+    .insert_resource(cli.units);
+
+    if cli.hide_ui {
+        app.insert_state(UIState::Hidden);
+    }
+
+    app.run();
 }
@@ -7,4 +7,5 @@ pub struct PanOrbitCameraBundle {
     pub camera: Camera3dBundle,
     pub state: PanOrbitState,
     pub settings: PanOrbitSettings,
+    pub fly_settings: FlySettings,
 }
@@ -14,6 +14,12 @@ pub struct PanOrbitState {
     pub upside_down: bool,
     pub pitch: f32,
     pub yaw: f32,
+    /// Pan speed carried over between frames, in pan units per second
+    pub pan_velocity: Vec2,
+    /// Orbit speed carried over between frames, in radians per second
+    pub orbit_velocity: Vec2,
+    /// Zoom speed carried over between frames, in exponent per second
+    pub zoom_velocity: f32,
 }
 
 #[derive(Component)]
@@ -36,8 +42,51 @@ pub struct PanOrbitSettings {
     pub scroll_line_sensitivity: f32,
     /// For devices with smooth scrolling, like touchpads
     pub scroll_pixel_sensitivity: f32,
+    /// How fast the camera stops after input is released, per second
+    /// (`f32::INFINITY` disables inertia)
+    pub damping: f32,
+    /// Closest the camera can get to its center
+    pub min_radius: f32,
+    /// Farthest the camera can get from its center
+    pub max_radius: f32,
+    /// Largest pitch angle in radians, keeps the camera from flipping over
+    pub pitch_limit: Option<f32>,
 }
 
+#[derive(Component)]
+pub struct FlySettings {
+    /// World units per second for every unit of distance from the galactic center
+    pub move_speed: f32,
+    /// Distance below which the speed stops shrinking
+    pub min_speed_distance: f32,
+    /// Speed multiplier while the boost key is held
+    pub boost_multiplier: f32,
+    /// Radians per pixel of mouse motion
+    pub look_sensitivity: f32,
+    /// Radians per second of roll
+    pub roll_speed: f32,
+    /// Mouse click to hold for looking around (`None` to always look)
+    pub look_key: Option<MouseButton>,
+    pub forward_key: KeyCode,
+    pub backward_key: KeyCode,
+    pub left_key: KeyCode,
+    pub right_key: KeyCode,
+    pub up_key: KeyCode,
+    pub down_key: KeyCode,
+    pub roll_left_key: KeyCode,
+    pub roll_right_key: KeyCode,
+    pub boost_key: KeyCode,
+}
+
+/// Camera on its way to a new orbit center
+#[derive(Component)]
+pub struct CameraFlight {
+    pub from_center: Vec3,
+    pub to_center: Vec3,
+    pub from_radius: f32,
+    pub to_radius: f32,
+    pub timer: Timer,
+}
 
 impl Default for PanOrbitState {
     fn default() -> Self {
@@ -47,6 +96,9 @@ impl Default for PanOrbitState {
             upside_down: false,
             pitch: 0.0,
             yaw: 0.0,
+            pan_velocity: Vec2::ZERO,
+            orbit_velocity: Vec2::ZERO,
+            zoom_velocity: 0.0,
         }
     }
 }
@@ -63,6 +115,32 @@ impl Default for PanOrbitSettings {
             scroll_action: Some(PanOrbitAction::Zoom),
             scroll_line_sensitivity: 16.0, 
             scroll_pixel_sensitivity: 1.0,
+            damping: 8.0,
+            min_radius: 0.5,
+            max_radius: 150.0,
+            pitch_limit: Some(89.0f32.to_radians()),
+        }
+    }
+}
+
+impl Default for FlySettings {
+    fn default() -> Self {
+        FlySettings {
+            move_speed: 0.5,
+            min_speed_distance: 1.0,
+            boost_multiplier: 4.0,
+            look_sensitivity: 0.1f32.to_radians(),
+            roll_speed: 90.0f32.to_radians(),
+            look_key: Some(MouseButton::Right),
+            forward_key: KeyCode::KeyW,
+            backward_key: KeyCode::KeyS,
+            left_key: KeyCode::KeyA,
+            right_key: KeyCode::KeyD,
+            up_key: KeyCode::Space,
+            down_key: KeyCode::ControlLeft,
+            roll_left_key: KeyCode::KeyQ,
+            roll_right_key: KeyCode::KeyE,
+            boost_key: KeyCode::ShiftLeft,
         }
     }
 }
@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+/// Moves the main camera to look at `center` from `radius` away
+#[derive(Event)]
+pub struct FocusCamera {
+    pub center: Vec3,
+    pub radius: f32,
+}
@@ -2,18 +2,56 @@ use bevy::prelude::*;
 
 use crate::scene::camera::systems::*;
 use crate::scene::camera::components::*;
+use crate::scene::camera::events::*;
 
 pub mod systems;
 pub mod components;
 pub mod bundles;
+pub mod events;
+
+#[derive(States, Clone, Copy, Default, Eq, PartialEq, Hash, Debug)]
+pub enum CameraMode {
+    #[default]
+    Orbit,
+    Fly,
+    Map,
+}
 
 pub struct CameraPlugin;
 
 impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, spawn_camera).add_systems(
-            Update,
-            pan_orbit_camera.run_if(any_with_component::<PanOrbitState>),
-        );
+        app.init_state::<CameraMode>()
+            .add_event::<FocusCamera>()
+            .add_systems(Startup, spawn_camera)
+            .add_systems(
+                Update,
+                (
+                    switch_camera_mode,
+                    pan_orbit_camera
+                        .run_if(not(in_state(CameraMode::Fly)))
+                        .run_if(any_with_component::<PanOrbitState>),
+                    fly_camera.run_if(in_state(CameraMode::Fly)),
+                    (focus_camera, follow_camera_flight)
+                        .chain()
+                        .after(pan_orbit_camera),
+                ),
+            )
+            .add_systems(
+                OnTransition {
+                    exited: CameraMode::Fly,
+                    entered: CameraMode::Orbit,
+                },
+                sync_orbit_with_transform,
+            )
+            .add_systems(
+                OnTransition {
+                    exited: CameraMode::Fly,
+                    entered: CameraMode::Map,
+                },
+                sync_orbit_with_transform,
+            )
+            .add_systems(OnEnter(CameraMode::Map), enter_map_mode)
+            .add_systems(OnExit(CameraMode::Map), exit_map_mode);
     }
 }
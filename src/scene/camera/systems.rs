@@ -2,10 +2,24 @@ use std::f32::consts::{FRAC_PI_2, PI, TAU};
 
 use bevy::prelude::*;
 use bevy::input::mouse::{MouseMotion, MouseScrollUnit, MouseWheel};
+use bevy::render::camera::ScalingMode;
+use bevy::ui::IsDefaultUiCamera;
+use bevy::window::PrimaryWindow;
 
 use crate::scene::camera::components::*;
 use crate::scene::camera::bundles::*;
+use crate::scene::camera::events::*;
+use crate::scene::camera::CameraMode;
+use crate::scene::minimap::components::Minimap;
 
+/// Velocities below this are treated as a full stop
+const VELOCITY_EPSILON: f32 = 1e-4;
+/// How long the camera takes to fly to a new focus
+const FLIGHT_SECONDS: f32 = 1.2;
+/// Key switching between the orbit and the free-fly camera
+const FLY_MODE_KEY: KeyCode = KeyCode::KeyF;
+/// Key switching between the orbit and the top-down map camera
+const MAP_MODE_KEY: KeyCode = KeyCode::KeyM;
 
 pub fn spawn_camera(mut commands: Commands) {
     let mut camera = PanOrbitCameraBundle::default();
@@ -15,16 +29,42 @@ pub fn spawn_camera(mut commands: Commands) {
     camera.state.pitch = -20.0f32.to_radians();
     camera.state.yaw = 0.0f32.to_radians();
 
-    commands.spawn(camera);
+    // -- The minimap is drawn last, keep the UI on the main view
+    commands.spawn((camera, IsDefaultUiCamera));
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn pan_orbit_camera(
+    time: Res<Time>,
+    mode: Res<State<CameraMode>>,
     kbd: Res<ButtonInput<KeyCode>>,
     ms: Res<ButtonInput<MouseButton>>,
     mut evr_motion: EventReader<MouseMotion>,
     mut evr_scroll: EventReader<MouseWheel>,
-    mut q_camera: Query<(&PanOrbitSettings, &mut PanOrbitState, &mut Transform)>,
+    mut q_camera: Query<(
+        &PanOrbitSettings,
+        &mut PanOrbitState,
+        &mut Transform,
+        &mut Projection,
+    )>,
+    q_interaction: Query<&Interaction>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    q_minimap: Query<&Camera, With<Minimap>>,
+    mut drag_on_minimap: Local<bool>,
 ) {
+    // -- A drag starting on the minimap recenters the view there instead
+    if ms.get_just_pressed().next().is_some() {
+        let cursor = q_window.get_single().ok().and_then(Window::cursor_position);
+
+        *drag_on_minimap = cursor.is_some_and(|cursor| {
+            q_minimap.iter().any(|camera| {
+                camera
+                    .logical_viewport_rect()
+                    .is_some_and(|rect| rect.contains(cursor))
+            })
+        });
+    }
+
     // First, accumulate the total amount of
     // mouse motion and scroll, from all pending events:
     let mut total_motion: Vec2 = evr_motion.read().map(|ev| ev.delta).sum();
@@ -33,6 +73,11 @@ pub fn pan_orbit_camera(
     // but events are in window/ui coordinates, which are Y-Down)
     total_motion.y = -total_motion.y;
 
+    // -- Dragging a slider or pressing a button shouldn't move the camera
+    if *drag_on_minimap || q_interaction.iter().any(|i| *i == Interaction::Pressed) {
+        total_motion = Vec2::ZERO;
+    }
+
     let mut total_scroll_lines = Vec2::ZERO;
     let mut total_scroll_pixels = Vec2::ZERO;
     for ev in evr_scroll.read() {
@@ -48,7 +93,7 @@ pub fn pan_orbit_camera(
         }
     }
 
-    for (settings, mut state, mut transform) in &mut q_camera {
+    for (settings, mut state, mut transform, mut projection) in &mut q_camera {
         // Check how much of each thing we need to apply.
         // Accumulate values from motion and scroll,
         // based on our configuration settings.
@@ -113,6 +158,39 @@ pub fn pan_orbit_camera(
             total_orbit.x = -total_orbit.x;
         }
 
+        // The map looks straight down, there is nothing to orbit
+        if *mode.get() == CameraMode::Map {
+            total_orbit = Vec2::ZERO;
+        }
+
+        // Blend this frame's input into the velocities carried
+        // over from previous frames. Each impulse is spread over
+        // the following frames, so the camera keeps gliding after
+        // the input stops but travels the same total distance.
+        let dt = time.delta_seconds();
+        if dt > 0.0 {
+            let decay = (-settings.damping * dt).exp();
+            let blend = (1.0 - decay) / dt;
+
+            state.pan_velocity = state.pan_velocity * decay + total_pan * blend;
+            state.orbit_velocity = state.orbit_velocity * decay + total_orbit * blend;
+            state.zoom_velocity = state.zoom_velocity * decay + total_zoom.y * blend;
+
+            if state.pan_velocity.length() < VELOCITY_EPSILON {
+                state.pan_velocity = Vec2::ZERO;
+            }
+            if state.orbit_velocity.length() < VELOCITY_EPSILON {
+                state.orbit_velocity = Vec2::ZERO;
+            }
+            if state.zoom_velocity.abs() < VELOCITY_EPSILON {
+                state.zoom_velocity = 0.0;
+            }
+
+            total_pan = state.pan_velocity * dt;
+            total_orbit = state.orbit_velocity * dt;
+            total_zoom = Vec2::new(0.0, state.zoom_velocity * dt);
+        }
+
         // Now we can actually do the things!
 
         let mut any = false;
@@ -129,6 +207,14 @@ pub fn pan_orbit_camera(
             // so we compute the exponential of our
             // accumulated value and multiply by that
             state.radius *= (-total_zoom.y).exp();
+            // keep out of the black hole and within sight of the galaxy
+            state.radius = state.radius.clamp(settings.min_radius, settings.max_radius);
+
+            // The orthographic map doesn't shrink with distance,
+            // so the radius drives its scale instead
+            if let Projection::Orthographic(ortho) = projection.as_mut() {
+                ortho.scale = state.radius;
+            }
         }
 
         // To ORBIT, we change our pitch and yaw values
@@ -143,11 +229,19 @@ pub fn pan_orbit_camera(
             if state.yaw < -PI {
                 state.yaw += TAU; // 2 * PI
             }
-            if state.pitch > PI {
-                state.pitch -= TAU; // 2 * PI
-            }
-            if state.pitch < -PI {
-                state.pitch += TAU; // 2 * PI
+            if let Some(limit) = settings.pitch_limit {
+                // stop at the limit instead of flipping over the pole
+                if state.pitch.abs() > limit {
+                    state.pitch = state.pitch.clamp(-limit, limit);
+                    state.orbit_velocity.y = 0.0;
+                }
+            } else {
+                if state.pitch > PI {
+                    state.pitch -= TAU; // 2 * PI
+                }
+                if state.pitch < -PI {
+                    state.pitch += TAU; // 2 * PI
+                }
             }
         }
 
@@ -175,3 +269,202 @@ pub fn pan_orbit_camera(
         }
     }
 }
+
+pub fn switch_camera_mode(
+    kbd: Res<ButtonInput<KeyCode>>,
+    mode: Res<State<CameraMode>>,
+    mut next_mode: ResMut<NextState<CameraMode>>,
+) {
+    if kbd.just_pressed(FLY_MODE_KEY) {
+        next_mode.set(match mode.get() {
+            CameraMode::Fly => CameraMode::Orbit,
+            _ => CameraMode::Fly,
+        });
+    }
+    if kbd.just_pressed(MAP_MODE_KEY) {
+        next_mode.set(match mode.get() {
+            CameraMode::Map => CameraMode::Orbit,
+            _ => CameraMode::Map,
+        });
+    }
+}
+
+/// Height of the map view per unit of orbit radius. Matches what the default
+/// perspective shows at the orbit center, so switching keeps the framing.
+fn map_view_height() -> f32 {
+    2.0 * (PerspectiveProjection::default().fov / 2.0).tan()
+}
+
+pub fn enter_map_mode(
+    mut q_camera: Query<(&mut PanOrbitState, &mut Transform, &mut Projection)>,
+) {
+    for (mut state, mut transform, mut projection) in &mut q_camera {
+        // -- Lock onto the galactic plane, looking straight down
+        state.center.y = 0.0;
+        state.pitch = -FRAC_PI_2;
+        state.orbit_velocity = Vec2::ZERO;
+
+        *projection = Projection::Orthographic(OrthographicProjection {
+            scaling_mode: ScalingMode::FixedVertical(map_view_height()),
+            scale: state.radius,
+            ..default()
+        });
+
+        transform.rotation = Quat::from_euler(EulerRot::YXZ, state.yaw, state.pitch, 0.0);
+        transform.translation = state.center + transform.back() * state.radius;
+    }
+}
+
+pub fn exit_map_mode(mut q_camera: Query<&mut Projection, With<PanOrbitState>>) {
+    for mut projection in &mut q_camera {
+        *projection = Projection::Perspective(PerspectiveProjection::default());
+    }
+}
+
+/// Picks the orbit up from wherever the camera currently is,
+/// so leaving fly mode doesn't jump back to the old orbit.
+pub fn sync_orbit_with_transform(
+    mut q_camera: Query<(&PanOrbitSettings, &mut PanOrbitState, &mut Transform)>,
+) {
+    for (settings, mut state, mut transform) in &mut q_camera {
+        let (yaw, pitch, _) = transform.rotation.to_euler(EulerRot::YXZ);
+
+        state.yaw = yaw;
+        state.pitch = match settings.pitch_limit {
+            Some(limit) => pitch.clamp(-limit, limit),
+            None => pitch,
+        };
+        state.center = transform.translation + transform.forward() * state.radius;
+        state.pan_velocity = Vec2::ZERO;
+        state.orbit_velocity = Vec2::ZERO;
+        state.zoom_velocity = 0.0;
+
+        // The orbit has no roll, level the camera out
+        transform.rotation = Quat::from_euler(EulerRot::YXZ, state.yaw, state.pitch, 0.0);
+        transform.translation = state.center + transform.back() * state.radius;
+    }
+}
+
+pub fn fly_camera(
+    time: Res<Time>,
+    kbd: Res<ButtonInput<KeyCode>>,
+    ms: Res<ButtonInput<MouseButton>>,
+    mut evr_motion: EventReader<MouseMotion>,
+    mut q_camera: Query<(&FlySettings, &mut Transform)>,
+) {
+    let total_motion: Vec2 = evr_motion.read().map(|ev| ev.delta).sum();
+    let dt = time.delta_seconds();
+
+    for (settings, mut transform) in &mut q_camera {
+        // -- Look around in the camera's own frame, so there is no gimbal lock
+        let looking = settings.look_key.map(|btn| ms.pressed(btn)).unwrap_or(true);
+        if looking && total_motion != Vec2::ZERO {
+            let look = total_motion * settings.look_sensitivity;
+            transform.rotate_local_y(-look.x);
+            transform.rotate_local_x(-look.y);
+        }
+
+        let mut roll = 0.0;
+        if kbd.pressed(settings.roll_left_key) {
+            roll += 1.0;
+        }
+        if kbd.pressed(settings.roll_right_key) {
+            roll -= 1.0;
+        }
+        if roll != 0.0 {
+            transform.rotate_local_z(roll * settings.roll_speed * dt);
+        }
+
+        // -- Move along the camera's own axes
+        let mut direction = Vec3::ZERO;
+        let bindings = [
+            (settings.forward_key, transform.forward()),
+            (settings.backward_key, transform.back()),
+            (settings.left_key, transform.left()),
+            (settings.right_key, transform.right()),
+            (settings.up_key, transform.up()),
+            (settings.down_key, transform.down()),
+        ];
+        for (key, axis) in bindings {
+            if kbd.pressed(key) {
+                direction += *axis;
+            }
+        }
+
+        if direction != Vec3::ZERO {
+            // -- Crawl near the core, cover the arms quickly farther out
+            let distance = transform.translation.length().max(settings.min_speed_distance);
+            let mut speed = settings.move_speed * distance;
+            if kbd.pressed(settings.boost_key) {
+                speed *= settings.boost_multiplier;
+            }
+
+            transform.translation += direction.normalize() * speed * dt;
+        }
+    }
+}
+
+pub fn focus_camera(
+    mut commands: Commands,
+    mode: Res<State<CameraMode>>,
+    mut evr_focus: EventReader<FocusCamera>,
+    mut q_camera: Query<(Entity, &PanOrbitSettings, &PanOrbitState, &mut Transform)>,
+) {
+    let Some(focus) = evr_focus.read().last() else {
+        return;
+    };
+
+    for (entity, settings, state, mut transform) in &mut q_camera {
+        let radius = focus.radius.clamp(settings.min_radius, settings.max_radius);
+
+        // -- The free-fly camera has no orbit to glide along, it jumps there
+        if *mode.get() == CameraMode::Fly {
+            transform.translation = focus.center + transform.back() * radius;
+            continue;
+        }
+
+        commands.entity(entity).insert(CameraFlight {
+            from_center: state.center,
+            to_center: focus.center,
+            from_radius: state.radius,
+            to_radius: radius,
+            timer: Timer::from_seconds(FLIGHT_SECONDS, TimerMode::Once),
+        });
+    }
+}
+
+pub fn follow_camera_flight(
+    mut commands: Commands,
+    time: Res<Time>,
+    mode: Res<State<CameraMode>>,
+    mut q_camera: Query<(
+        Entity,
+        &mut CameraFlight,
+        &mut PanOrbitState,
+        &mut Transform,
+        &mut Projection,
+    )>,
+) {
+    for (entity, mut flight, mut state, mut transform, mut projection) in &mut q_camera {
+        flight.timer.tick(time.delta());
+
+        // -- Ease in and out, so the flight doesn't start or stop with a jolt
+        let t = flight.timer.fraction();
+        let t = t * t * (3.0 - 2.0 * t);
+
+        state.center = flight.from_center.lerp(flight.to_center, t);
+        // -- Zoom exponentially, like the scroll wheel does
+        state.radius = flight.from_radius * (flight.to_radius / flight.from_radius).powf(t);
+        state.pan_velocity = Vec2::ZERO;
+        state.zoom_velocity = 0.0;
+
+        if let Projection::Orthographic(ortho) = projection.as_mut() {
+            ortho.scale = state.radius;
+        }
+        transform.translation = state.center + transform.back() * state.radius;
+
+        if flight.timer.finished() || *mode.get() == CameraMode::Fly {
+            commands.entity(entity).remove::<CameraFlight>();
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+
+use crate::scene::orbiting_body::components::BodyKind;
+
+/// Syllables star names are made of
+const SYLLABLES: [&str; 16] = [
+    "al", "be", "cor", "da", "el", "fen", "gi", "hal", "ka", "lor", "mi", "nor", "ra", "sel", "tu",
+    "ve",
+];
+
+/// Catalog identity of a body, the same for the same configuration
+#[derive(Component, Debug, Clone)]
+pub struct CatalogEntry {
+    pub id: u32,
+    pub name: String,
+}
+
+impl CatalogEntry {
+    pub fn new(id: u32, kind: BodyKind) -> Self {
+        let name = match kind {
+            BodyKind::Star => star_name(id),
+            _ => format!("{} {}", kind, id),
+        };
+
+        Self { id, name }
+    }
+
+    /// Name followed by the catalog number, e.g. "Korsel (#1042)"
+    pub fn label(&self) -> String {
+        format!("{} (#{})", self.name, self.id)
+    }
+
+    /// Label telling the kind too, which only star names don't already
+    pub fn describe(&self, kind: BodyKind) -> String {
+        match kind {
+            BodyKind::Star => format!("{}, {}", self.label(), kind),
+            _ => self.label(),
+        }
+    }
+}
+
+/// Two or three syllables picked from a hash of the id
+fn star_name(id: u32) -> String {
+    let mut hash = id.wrapping_add(1).wrapping_mul(0x9E37_79B9);
+    let syllables = 2 + (hash >> 31) as usize;
+
+    let mut name = String::new();
+    for _ in 0..syllables {
+        name.push_str(SYLLABLES[(hash & 0xF) as usize]);
+        hash = hash.rotate_right(5).wrapping_mul(0x85EB_CA6B);
+    }
+
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => name,
+    }
+}
@@ -4,6 +4,10 @@ use crate::scene::center_body::systems::*;
 
 mod systems;
 
+/// Marks the black hole and its accretion disk
+#[derive(Component)]
+pub struct CenterBody;
+
 pub struct CenterBodyPlugin;
 
 impl Plugin for CenterBodyPlugin {
@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
+use crate::scene::center_body::CenterBody;
+
 pub fn spawn_center_body(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
@@ -19,16 +21,22 @@ pub fn spawn_center_body(
         ..Default::default()
     });
 
-    commands.spawn(PbrBundle {
-        mesh: accretion_disk_mesh,
-        material: accretion_disk_material,
-        transform: Transform::from_xyz(0.0, 0.0, 0.0),
-        ..default()
-    });
-    commands.spawn(PbrBundle {
-        mesh: black_hole_mesh,
-        material: black_hole_material,
-        transform: Transform::from_xyz(0.0, 0.0, 0.0),
-        ..default()
-    });
+    commands.spawn((
+        PbrBundle {
+            mesh: accretion_disk_mesh,
+            material: accretion_disk_material,
+            transform: Transform::from_xyz(0.0, 0.0, 0.0),
+            ..default()
+        },
+        CenterBody,
+    ));
+    commands.spawn((
+        PbrBundle {
+            mesh: black_hole_mesh,
+            material: black_hole_material,
+            transform: Transform::from_xyz(0.0, 0.0, 0.0),
+            ..default()
+        },
+        CenterBody,
+    ));
 }
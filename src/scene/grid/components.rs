@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+
+/// Everything spawned for the grid, despawned when it's turned off
+#[derive(Component)]
+pub struct GridOverlay;
+
+/// Text pinned to a point in the galactic plane
+#[derive(Component)]
+pub struct GridLabel {
+    pub position: Vec3,
+}
+
+#[derive(Component)]
+pub struct ScaleBar;
+
+#[derive(Component)]
+pub struct ScaleBarText;
@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+
+use crate::scene::grid::resources::GridSettings;
+use crate::scene::grid::systems::*;
+
+pub mod components;
+pub mod resources;
+pub mod systems;
+
+pub struct GridPlugin;
+
+impl Plugin for GridPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GridSettings>().add_systems(
+            Update,
+            (
+                spawn_grid_overlay.run_if(resource_changed::<GridSettings>),
+                (draw_grid, place_grid_labels, update_scale_bar).run_if(grid_enabled),
+            ),
+        );
+    }
+}
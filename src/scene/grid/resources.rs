@@ -0,0 +1,35 @@
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+#[derive(Resource, Clone)]
+pub struct GridSettings {
+    pub enabled: bool,
+    /// Radii of the concentric rings, in scene units
+    pub ring_radii: Vec<f32>,
+    /// Angle between two neighbouring spokes, in radians
+    pub spoke_step: f32,
+}
+
+impl GridSettings {
+    pub fn outer_radius(&self) -> f32 {
+        self.ring_radii.iter().copied().fold(0.0, f32::max)
+    }
+
+    pub fn spoke_angles(&self) -> impl Iterator<Item = f32> {
+        let count = (2.0 * PI / self.spoke_step).round() as u32;
+        let step = self.spoke_step;
+
+        (0..count).map(move |i| i as f32 * step)
+    }
+}
+
+impl Default for GridSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            ring_radii: vec![5.0, 10.0, 15.0, 20.0, 25.0],
+            spoke_step: 30.0f32.to_radians(),
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use bevy::prelude::*;
+
+use crate::configuration::resources::MapUnits;
+use crate::scene::camera::components::PanOrbitState;
+use crate::scene::grid::components::*;
+use crate::scene::grid::resources::GridSettings;
+use crate::ui::styles::*;
+
+const GRID_COLOR: Color = Color::srgba(0.4, 0.6, 1.0, 0.35);
+const GRID_LABEL_COLOR: Color = Color::srgba(0.6, 0.8, 1.0, 0.8);
+/// How long the scale bar tries to be, in logical pixels
+const SCALE_BAR_TARGET_WIDTH: f32 = 150.0;
+
+pub fn grid_enabled(settings: Res<GridSettings>) -> bool {
+    settings.enabled
+}
+
+pub fn spawn_grid_overlay(
+    mut commands: Commands,
+    settings: Res<GridSettings>,
+    asset_server: Res<AssetServer>,
+    overlay_query: Query<Entity, With<GridOverlay>>,
+) {
+    for entity in overlay_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    if !settings.enabled {
+        return;
+    }
+
+    let mut label_style = get_text_style(&asset_server, 14.0);
+    label_style.color = GRID_LABEL_COLOR;
+
+    // -- Ring radii along the zero spoke
+    for &radius in &settings.ring_radii {
+        spawn_label(
+            &mut commands,
+            format!("{:.1}", radius),
+            Vec3::new(radius, 0.0, 0.0),
+            &label_style,
+        );
+    }
+
+    // -- Angles just past the outermost ring
+    let label_radius = settings.outer_radius() * 1.05;
+    for angle in settings.spoke_angles() {
+        spawn_label(
+            &mut commands,
+            format!("{:.0}°", angle.to_degrees()),
+            spoke_direction(angle) * label_radius,
+            &label_style,
+        );
+    }
+
+    spawn_scale_bar(&mut commands, &asset_server);
+}
+
+fn spawn_label(commands: &mut Commands, value: String, position: Vec3, style: &TextStyle) {
+    let label = TextBundle {
+        text: Text::from_section(value, style.clone()),
+        style: GRID_LABEL_STYLE,
+        visibility: Visibility::Hidden,
+        ..default()
+    };
+
+    commands.spawn((label, GridLabel { position }, GridOverlay));
+}
+
+fn spawn_scale_bar(commands: &mut Commands, asset_server: &Res<AssetServer>) {
+    let wrapper = NodeBundle {
+        style: SCALE_BAR_WRAPPER_STYLE,
+        ..default()
+    };
+    let bar = NodeBundle {
+        style: SCALE_BAR_STYLE,
+        border_color: UI_BORDER_COLOR,
+        ..default()
+    };
+    let text = TextBundle {
+        text: Text::from_section("", get_text_style(asset_server, 14.0)),
+        ..default()
+    };
+
+    commands
+        .spawn((wrapper, GridOverlay))
+        .with_children(|parent| {
+            parent.spawn((bar, ScaleBar));
+            parent.spawn((text, ScaleBarText));
+        });
+}
+
+pub fn draw_grid(settings: Res<GridSettings>, mut gizmos: Gizmos) {
+    for &radius in &settings.ring_radii {
+        gizmos.circle(Vec3::ZERO, Dir3::Y, radius, GRID_COLOR);
+    }
+
+    let outer_radius = settings.outer_radius();
+    for angle in settings.spoke_angles() {
+        gizmos.line(
+            Vec3::ZERO,
+            spoke_direction(angle) * outer_radius,
+            GRID_COLOR,
+        );
+    }
+}
+
+pub fn place_grid_labels(
+    q_camera: Query<(&Camera, &GlobalTransform), With<PanOrbitState>>,
+    mut label_query: Query<(&GridLabel, &mut Style, &mut Visibility)>,
+) {
+    let Ok((camera, camera_transform)) = q_camera.get_single() else {
+        return;
+    };
+
+    for (label, mut style, mut visibility) in &mut label_query {
+        match camera.world_to_viewport(camera_transform, label.position) {
+            Some(position) => {
+                style.left = Val::Px(position.x);
+                style.top = Val::Px(position.y);
+                *visibility = Visibility::Visible;
+            }
+            None => {
+                *visibility = Visibility::Hidden;
+            }
+        }
+    }
+}
+
+pub fn update_scale_bar(
+    units: Res<MapUnits>,
+    q_camera: Query<(&Camera, &GlobalTransform, &PanOrbitState)>,
+    mut bar_query: Query<&mut Style, With<ScaleBar>>,
+    mut text_query: Query<&mut Text, With<ScaleBarText>>,
+) {
+    let Ok((camera, camera_transform, state)) = q_camera.get_single() else {
+        return;
+    };
+
+    // -- Measure one unit across the screen at the point the camera orbits,
+    // -- which works the same for both perspective and orthographic projections
+    let right = camera_transform.right();
+    let pixels_per_unit = camera
+        .world_to_viewport(camera_transform, state.center)
+        .zip(camera.world_to_viewport(camera_transform, state.center + *right))
+        .map(|(start, end)| start.distance(end))
+        .filter(|pixels| *pixels > 0.0);
+
+    let Some(pixels_per_unit) = pixels_per_unit else {
+        return;
+    };
+
+    // -- Round in the displayed unit, then size the bar back in scene units
+    let scene_units = SCALE_BAR_TARGET_WIDTH / pixels_per_unit;
+    let units_per_scene_unit = units.convert(1.0);
+    let length = round_scale_length(scene_units * units_per_scene_unit);
+
+    for mut style in &mut bar_query {
+        style.width = Val::Px(length / units_per_scene_unit * pixels_per_unit);
+    }
+    for mut text in &mut text_query {
+        text.sections[0].value = format!("{} {}", format_scale_length(length), units.unit.symbol());
+    }
+}
+
+fn spoke_direction(angle: f32) -> Vec3 {
+    Vec3::new(angle.cos(), 0.0, -angle.sin())
+}
+
+/// Rounds down to 1, 2 or 5 times a power of ten, the lengths a ruler would show
+fn round_scale_length(length: f32) -> f32 {
+    let magnitude = 10f32.powf(length.log10().floor());
+    let leading = length / magnitude;
+
+    let leading = if leading >= 5.0 {
+        5.0
+    } else if leading >= 2.0 {
+        2.0
+    } else {
+        1.0
+    };
+
+    leading * magnitude
+}
+
+fn format_scale_length(length: f32) -> String {
+    if length >= 1.0 {
+        format!("{:.0}", length)
+    } else {
+        format!("{}", (length * 1_000.0).round() / 1_000.0)
+    }
+}
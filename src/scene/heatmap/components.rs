@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+
+/// Everything spawned for the heatmap: the map itself and its legend
+#[derive(Component)]
+pub struct HeatmapOverlay;
+
+#[derive(Component)]
+pub struct HeatmapMap;
+
+#[derive(Component)]
+pub struct HeatmapLegendText;
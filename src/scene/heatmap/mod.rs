@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+use crate::scene::heatmap::resources::HeatmapSettings;
+use crate::scene::heatmap::systems::*;
+
+pub mod components;
+pub mod resources;
+pub mod systems;
+
+pub struct HeatmapPlugin;
+
+impl Plugin for HeatmapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HeatmapSettings>().add_systems(
+            Update,
+            (
+                control_heatmap,
+                show_heatmap.run_if(resource_changed::<HeatmapSettings>),
+                paint_heatmap.run_if(heatmap_enabled),
+            )
+                .chain(),
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+
+use crate::scene::BodyKind;
+
+/// What the heatmap counts in each cell
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HeatmapMode {
+    StarDensity,
+    DustDensity,
+    HIIDensity,
+    /// Mean `dimming_channel` of every body in the cell
+    Brightness,
+}
+
+impl HeatmapMode {
+    pub fn next(self) -> Self {
+        match self {
+            HeatmapMode::StarDensity => HeatmapMode::DustDensity,
+            HeatmapMode::DustDensity => HeatmapMode::HIIDensity,
+            HeatmapMode::HIIDensity => HeatmapMode::Brightness,
+            HeatmapMode::Brightness => HeatmapMode::StarDensity,
+        }
+    }
+
+    /// Kind of body counted by the density modes
+    pub fn counted_kind(self) -> Option<BodyKind> {
+        match self {
+            HeatmapMode::StarDensity => Some(BodyKind::Star),
+            HeatmapMode::DustDensity => Some(BodyKind::Dust),
+            HeatmapMode::HIIDensity => Some(BodyKind::HII),
+            HeatmapMode::Brightness => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            HeatmapMode::StarDensity => "Star density",
+            HeatmapMode::DustDensity => "Dust density",
+            HeatmapMode::HIIDensity => "HII density",
+            HeatmapMode::Brightness => "Mean brightness",
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct HeatmapSettings {
+    pub enabled: bool,
+    pub mode: HeatmapMode,
+    /// Cells per side of the grid the bodies are binned into
+    pub resolution: u32,
+    /// Whether the heatmap keeps following the orbiting bodies
+    pub live: bool,
+    /// How often a live heatmap is binned again
+    pub refresh: Timer,
+}
+
+impl Default for HeatmapSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            mode: HeatmapMode::StarDensity,
+            resolution: 64,
+            live: true,
+            refresh: Timer::from_seconds(0.5, TimerMode::Repeating),
+        }
+    }
+}
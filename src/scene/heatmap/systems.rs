@@ -0,0 +1,303 @@
+use bevy::prelude::*;
+
+use crate::configuration::resources::{Configuration, MapUnits};
+use crate::scene::heatmap::components::*;
+use crate::scene::heatmap::resources::*;
+use crate::scene::orbiting_body::events::BodiesReshaped;
+use crate::scene::plane_map::*;
+use crate::scene::*;
+use crate::ui::styles::*;
+
+const HEATMAP_TOGGLE_KEY: KeyCode = KeyCode::KeyJ;
+const HEATMAP_MODE_KEY: KeyCode = KeyCode::KeyK;
+const HEATMAP_LIVE_KEY: KeyCode = KeyCode::KeyL;
+/// Keeps the heatmap under the bodies and the territory
+const HEATMAP_HEIGHT: f32 = -0.03;
+/// Color stops from the lowest to the highest value
+const HEATMAP_RAMP: [Srgba; 4] = [
+    Srgba::rgb(0.1, 0.0, 0.35),
+    Srgba::rgb(0.6, 0.1, 0.55),
+    Srgba::rgb(1.0, 0.5, 0.1),
+    Srgba::rgb(1.0, 1.0, 0.6),
+];
+const HEATMAP_MAX_ALPHA: f32 = 0.8;
+/// Color swatches drawn in the legend
+const LEGEND_SWATCHES: usize = 8;
+
+pub fn heatmap_enabled(settings: Res<HeatmapSettings>) -> bool {
+    settings.enabled
+}
+
+pub fn control_heatmap(kbd: Res<ButtonInput<KeyCode>>, mut settings: ResMut<HeatmapSettings>) {
+    if kbd.just_pressed(HEATMAP_TOGGLE_KEY) {
+        settings.enabled = !settings.enabled;
+    }
+    if kbd.just_pressed(HEATMAP_MODE_KEY) && settings.enabled {
+        settings.mode = settings.mode.next();
+    }
+    if kbd.just_pressed(HEATMAP_LIVE_KEY) && settings.enabled {
+        settings.live = !settings.live;
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn show_heatmap(
+    mut commands: Commands,
+    settings: Res<HeatmapSettings>,
+    configuration: Res<Configuration>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut images: ResMut<Assets<Image>>,
+    asset_server: Res<AssetServer>,
+    overlay_query: Query<Entity, With<HeatmapOverlay>>,
+) {
+    for entity in overlay_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    if !settings.enabled {
+        return;
+    }
+
+    spawn_plane_map(
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        &mut images,
+        settings.resolution,
+        configuration.galaxy_radius(),
+        HEATMAP_HEIGHT,
+    )
+    .insert((HeatmapMap, HeatmapOverlay));
+
+    let wrapper = NodeBundle {
+        style: HEATMAP_LEGEND_STYLE,
+        background_color: UI_BACKGROUND_COLOR,
+        border_radius: UI_BORDER_RADIUS,
+        ..default()
+    };
+    let text = TextBundle {
+        text: Text::from_section("", get_text_style(&asset_server, 16.0)),
+        ..default()
+    };
+
+    commands
+        .spawn((wrapper, HeatmapOverlay))
+        .with_children(|parent| {
+            parent.spawn((text, HeatmapLegendText));
+            parent.spawn(NodeBundle::default()).with_children(|parent| {
+                for swatch in 0..LEGEND_SWATCHES {
+                    let value = swatch as f32 / (LEGEND_SWATCHES - 1) as f32;
+
+                    parent.spawn(NodeBundle {
+                        style: HEATMAP_SWATCH_STYLE,
+                        background_color: BackgroundColor(ramp(value).into()),
+                        ..default()
+                    });
+                }
+            });
+        });
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn paint_heatmap(
+    time: Res<Time>,
+    mut settings: ResMut<HeatmapSettings>,
+    configuration: Res<Configuration>,
+    units: Res<MapUnits>,
+    mut images: ResMut<Assets<Image>>,
+    mut map_query: Query<(&mut PlaneMap, &mut Transform), With<HeatmapMap>>,
+    mut text_query: Query<&mut Text, With<HeatmapLegendText>>,
+    q_bodies: Query<(&OrbitingBody, &BodyKind)>,
+    added_query: Query<(), Added<OrbitingBody>>,
+    mut evr_reshaped: EventReader<BodiesReshaped>,
+) {
+    // -- Ticking must not count as a settings change, which would respawn the map
+    let ticked = settings
+        .bypass_change_detection()
+        .refresh
+        .tick(time.delta())
+        .just_finished();
+    let Ok((mut map, mut transform)) = map_query.get_single_mut() else {
+        return;
+    };
+    let reshaped = evr_reshaped.read().count() > 0;
+    let outdated =
+        map.is_added() || !added_query.is_empty() || reshaped || (ticked && settings.live);
+    if !outdated {
+        return;
+    }
+
+    // -- The galaxy may have grown or shrunk since the map was made
+    let radius = configuration.galaxy_radius();
+    if map.extent != radius {
+        map.extent = radius;
+        *transform = map.transform(HEATMAP_HEIGHT);
+    }
+
+    // -- Bin the bodies by the cell under them
+    let resolution = map.resolution;
+    let cell_size = 2.0 * map.extent / resolution as f32;
+    let counted_kind = settings.mode.counted_kind();
+    let mut counts = vec![0u32; (resolution * resolution) as usize];
+    let mut brightness = vec![0.0f32; (resolution * resolution) as usize];
+
+    for (body, kind) in &q_bodies {
+        if counted_kind.is_some_and(|counted| counted != *kind) {
+            continue;
+        }
+
+        let Some(index) = cell_index(body.position().xz(), map.extent, resolution) else {
+            continue;
+        };
+        counts[index] += 1;
+        brightness[index] += body.dimming_channel;
+    }
+
+    let (values, legend) = scale_cells(settings.mode, &counts, &brightness);
+
+    let status = if settings.live { "live" } else { "paused" };
+    let value = format!(
+        "{} ({})\n{}, cell {}",
+        settings.mode.label(),
+        status,
+        legend,
+        units.format(cell_size),
+    );
+    for mut text in &mut text_query {
+        text.sections[0].value = value.clone();
+    }
+
+    let Some(image) = images.get_mut(&map.image) else {
+        return;
+    };
+
+    for (index, value) in values.iter().enumerate() {
+        let pixel = &mut image.data[index * 4..index * 4 + 4];
+
+        let color = match value {
+            Some(value) => ramp(*value).with_alpha(HEATMAP_MAX_ALPHA * value.max(0.25)),
+            None => Srgba::NONE,
+        };
+        pixel.copy_from_slice(&color.to_u8_array());
+    }
+}
+
+/// Cell of a `resolution` by `resolution` grid covering `-extent..extent`
+/// that `position` falls in, by index
+fn cell_index(position: Vec2, extent: f32, resolution: u32) -> Option<usize> {
+    let cell = (position + Vec2::splat(extent)) / (2.0 * extent / resolution as f32);
+    let (column, row) = (cell.x.floor(), cell.y.floor());
+    if column < 0.0 || row < 0.0 || column >= resolution as f32 || row >= resolution as f32 {
+        return None;
+    }
+
+    Some(row as usize * resolution as usize + column as usize)
+}
+
+/// Values scaled to 0..1 over the occupied cells, and the legend telling what they are
+fn scale_cells(
+    mode: HeatmapMode,
+    counts: &[u32],
+    brightness: &[f32],
+) -> (Vec<Option<f32>>, String) {
+    match mode {
+        HeatmapMode::Brightness => {
+            let means: Vec<Option<f32>> = counts
+                .iter()
+                .zip(brightness)
+                .map(|(&count, &sum)| (count > 0).then(|| sum / count as f32))
+                .collect();
+            let (low, high) = means
+                .iter()
+                .flatten()
+                .fold((f32::MAX, f32::MIN), |(low, high), &mean| {
+                    (low.min(mean), high.max(mean))
+                });
+            let span = (high - low).max(f32::EPSILON);
+
+            let values = means
+                .iter()
+                .map(|mean| mean.map(|mean| (mean - low) / span))
+                .collect::<Vec<_>>();
+            let legend = if low <= high {
+                format!("Brightness {:.2} to {:.2}", low, high)
+            } else {
+                String::from("No bodies")
+            };
+
+            (values, legend)
+        }
+        _ => {
+            let peak = counts.iter().copied().max().unwrap_or(0).max(1);
+
+            let values = counts
+                .iter()
+                .map(|&count| (count > 0).then(|| count as f32 / peak as f32))
+                .collect::<Vec<_>>();
+            let legend = format!("Up to {} per cell", peak);
+
+            (values, legend)
+        }
+    }
+}
+
+/// Color of a 0..1 value along the heatmap ramp
+fn ramp(value: f32) -> Srgba {
+    let scaled = value.clamp(0.0, 1.0) * (HEATMAP_RAMP.len() - 1) as f32;
+    let index = (scaled.floor() as usize).min(HEATMAP_RAMP.len() - 2);
+
+    HEATMAP_RAMP[index].mix(&HEATMAP_RAMP[index + 1], scaled - index as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bodies_fall_in_the_cell_under_them() {
+        // -- Four cells of 1 x 1 over -2..2
+        assert_eq!(cell_index(Vec2::new(-1.5, -1.5), 2.0, 4), Some(0));
+        assert_eq!(cell_index(Vec2::new(1.5, -1.5), 2.0, 4), Some(3));
+        assert_eq!(cell_index(Vec2::new(-1.5, 1.5), 2.0, 4), Some(12));
+        assert_eq!(cell_index(Vec2::new(0.0, 0.0), 2.0, 4), Some(10));
+    }
+
+    #[test]
+    fn bodies_off_the_grid_are_left_out() {
+        assert_eq!(cell_index(Vec2::new(-2.5, 0.0), 2.0, 4), None);
+        assert_eq!(cell_index(Vec2::new(0.0, 2.0), 2.0, 4), None);
+    }
+
+    #[test]
+    fn densities_are_scaled_to_the_peak() {
+        let (values, legend) = scale_cells(HeatmapMode::StarDensity, &[0, 2, 4], &[0.0; 3]);
+
+        assert_eq!(values, [None, Some(0.5), Some(1.0)]);
+        assert_eq!(legend, "Up to 4 per cell");
+    }
+
+    #[test]
+    fn brightness_is_scaled_between_the_means() {
+        let (values, legend) =
+            scale_cells(HeatmapMode::Brightness, &[2, 0, 1, 4], &[0.4, 0.0, 0.8, 2.0]);
+
+        // -- Means of 0.2, none, 0.8 and 0.5
+        assert_eq!(values[0], Some(0.0));
+        assert_eq!(values[1], None);
+        assert_eq!(values[2], Some(1.0));
+        assert!((values[3].unwrap() - 0.5).abs() < 1e-6);
+        assert_eq!(legend, "Brightness 0.20 to 0.80");
+
+        let (values, legend) = scale_cells(HeatmapMode::Brightness, &[0, 0], &[0.0, 0.0]);
+        assert_eq!(values, [None, None]);
+        assert_eq!(legend, "No bodies");
+    }
+
+    #[test]
+    fn density_modes_count_their_kind() {
+        assert_eq!(HeatmapMode::StarDensity.counted_kind(), Some(BodyKind::Star));
+        assert_eq!(HeatmapMode::HIIDensity.counted_kind(), Some(BodyKind::HII));
+        assert_eq!(HeatmapMode::Brightness.counted_kind(), None);
+    }
+}
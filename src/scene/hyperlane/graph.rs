@@ -0,0 +1,278 @@
+use std::collections::{HashMap, HashSet};
+
+use bevy::math::DVec2;
+use bevy::prelude::*;
+
+/// Undirected edge between two point indices, smaller index first
+pub type Edge = (usize, usize);
+
+fn edge(a: usize, b: usize) -> Edge {
+    (a.min(b), a.max(b))
+}
+
+/// Delaunay triangulation edges, built incrementally with Bowyer-Watson
+pub fn delaunay(points: &[Vec2]) -> Vec<Edge> {
+    if points.len() < 3 {
+        return (1..points.len()).map(|b| edge(0, b)).collect();
+    }
+
+    // -- Double precision keeps the circumcircle tests stable for nearly collinear points
+    let mut vertices: Vec<DVec2> = points.iter().map(|point| point.as_dvec2()).collect();
+
+    // -- Super triangle enclosing every point, removed at the end
+    let (min, max) = vertices
+        .iter()
+        .fold((vertices[0], vertices[0]), |(min, max), point| {
+            (min.min(*point), max.max(*point))
+        });
+    let size = (max - min).max_element().max(1.0);
+    let mid = (min + max) / 2.0;
+    let first_super = vertices.len();
+    vertices.push(mid + DVec2::new(-20.0 * size, -size));
+    vertices.push(mid + DVec2::new(0.0, 20.0 * size));
+    vertices.push(mid + DVec2::new(20.0 * size, -size));
+
+    let mut triangles = vec![Triangle::new(
+        [first_super, first_super + 1, first_super + 2],
+        &vertices,
+    )];
+
+    for index in 0..points.len() {
+        let point = vertices[index];
+
+        // -- Carve out every triangle whose circumcircle holds the point
+        let mut cavity: HashMap<Edge, u32> = HashMap::new();
+        triangles.retain(|triangle| {
+            if !triangle.circumcircle_contains(point) {
+                return true;
+            }
+            for (a, b) in triangle.edges() {
+                *cavity.entry(edge(a, b)).or_default() += 1;
+            }
+            false
+        });
+
+        // -- And fan the cavity boundary out to the point
+        for ((a, b), count) in cavity {
+            if count == 1 {
+                triangles.push(Triangle::new([a, b, index], &vertices));
+            }
+        }
+    }
+
+    let mut edges = HashSet::new();
+    for triangle in &triangles {
+        for (a, b) in triangle.edges() {
+            if a < first_super && b < first_super {
+                edges.insert(edge(a, b));
+            }
+        }
+    }
+
+    edges.into_iter().collect()
+}
+
+/// Keeps the candidate edges with no third point closer to both of their ends.
+/// The relative neighborhood graph is a subgraph of the Delaunay triangulation,
+/// so its edges are the natural candidates.
+pub fn relative_neighborhood(points: &[Vec2], candidates: &[Edge], cell_size: f32) -> Vec<Edge> {
+    let grid = SpatialGrid::new(points, cell_size);
+
+    candidates
+        .iter()
+        .copied()
+        .filter(|&(a, b)| {
+            let length = points[a].distance(points[b]);
+
+            !grid.within(points, points[a], length).any(|c| {
+                c != a
+                    && c != b
+                    && points[a].distance(points[c]) < length
+                    && points[b].distance(points[c]) < length
+            })
+        })
+        .collect()
+}
+
+/// Connects every point to its `k` closest neighbors within `max_length`
+pub fn nearest_neighbors(points: &[Vec2], k: usize, max_length: f32) -> Vec<Edge> {
+    let grid = SpatialGrid::new(points, max_length / 2.0);
+    let mut edges = HashSet::new();
+
+    for (a, point) in points.iter().enumerate() {
+        let mut neighbors: Vec<(f32, usize)> = grid
+            .within(points, *point, max_length)
+            .filter(|b| *b != a)
+            .map(|b| (point.distance(points[b]), b))
+            .collect();
+        neighbors.sort_by(|x, y| x.0.total_cmp(&y.0));
+
+        for (_, b) in neighbors.into_iter().take(k) {
+            edges.insert(edge(a, b));
+        }
+    }
+
+    edges.into_iter().collect()
+}
+
+struct Triangle {
+    vertices: [usize; 3],
+    center: DVec2,
+    radius_squared: f64,
+}
+
+impl Triangle {
+    fn new(vertices: [usize; 3], points: &[DVec2]) -> Self {
+        let [a, b, c] = vertices.map(|index| points[index]);
+        let d = 2.0 * (a.x * (b.y - c.y) + b.x * (c.y - a.y) + c.x * (a.y - b.y));
+
+        // -- Degenerate triangles are replaced by the first point that comes near
+        if d.abs() < f64::EPSILON {
+            return Self {
+                vertices,
+                center: (a + b + c) / 3.0,
+                radius_squared: f64::INFINITY,
+            };
+        }
+
+        let (a2, b2, c2) = (a.length_squared(), b.length_squared(), c.length_squared());
+        let center = DVec2::new(
+            (a2 * (b.y - c.y) + b2 * (c.y - a.y) + c2 * (a.y - b.y)) / d,
+            (a2 * (c.x - b.x) + b2 * (a.x - c.x) + c2 * (b.x - a.x)) / d,
+        );
+
+        Self {
+            vertices,
+            center,
+            radius_squared: center.distance_squared(a),
+        }
+    }
+
+    fn circumcircle_contains(&self, point: DVec2) -> bool {
+        self.center.distance_squared(point) < self.radius_squared
+    }
+
+    fn edges(&self) -> [(usize, usize); 3] {
+        let [a, b, c] = self.vertices;
+
+        [(a, b), (b, c), (c, a)]
+    }
+}
+
+/// Buckets points into square cells for neighborhood lookups
+pub struct SpatialGrid {
+    cell_size: f32,
+    cells: HashMap<(i32, i32), Vec<usize>>,
+}
+
+impl SpatialGrid {
+    pub fn new(points: &[Vec2], cell_size: f32) -> Self {
+        let mut grid = Self {
+            cell_size: cell_size.max(f32::EPSILON),
+            cells: HashMap::new(),
+        };
+
+        for (index, point) in points.iter().enumerate() {
+            let cell = grid.cell(*point);
+            grid.cells.entry(cell).or_default().push(index);
+        }
+
+        grid
+    }
+
+    fn cell(&self, point: Vec2) -> (i32, i32) {
+        (
+            (point.x / self.cell_size).floor() as i32,
+            (point.y / self.cell_size).floor() as i32,
+        )
+    }
+
+    /// Indices of the points no farther than `radius` from `center`
+    pub fn within<'a>(
+        &'a self,
+        points: &'a [Vec2],
+        center: Vec2,
+        radius: f32,
+    ) -> impl Iterator<Item = usize> + 'a {
+        let (min_x, min_y) = self.cell(center - Vec2::splat(radius));
+        let (max_x, max_y) = self.cell(center + Vec2::splat(radius));
+
+        (min_x..=max_x)
+            .flat_map(move |x| (min_y..=max_y).map(move |y| (x, y)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .copied()
+            .filter(move |index| points[*index].distance(center) <= radius)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(mut edges: Vec<Edge>) -> Vec<Edge> {
+        edges.sort();
+        edges
+    }
+
+    fn square() -> Vec<Vec2> {
+        vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 1.0),
+        ]
+    }
+
+    #[test]
+    fn delaunay_splits_a_square_with_one_diagonal() {
+        let edges = sorted(delaunay(&square()));
+
+        assert_eq!(edges.len(), 5);
+        for side in [(0, 1), (1, 2), (2, 3), (0, 3)] {
+            assert!(edges.contains(&side), "missing side {:?}", side);
+        }
+        assert!(edges.contains(&(0, 2)) != edges.contains(&(1, 3)));
+    }
+
+    #[test]
+    fn delaunay_chains_collinear_points() {
+        let points: Vec<Vec2> = (0..4).map(|x| Vec2::new(x as f32, 0.0)).collect();
+
+        assert_eq!(sorted(delaunay(&points)), vec![(0, 1), (1, 2), (2, 3)]);
+    }
+
+    #[test]
+    fn delaunay_of_few_points() {
+        assert!(delaunay(&[]).is_empty());
+        assert_eq!(delaunay(&[Vec2::ZERO, Vec2::X]), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn relative_neighborhood_drops_the_square_diagonal() {
+        let points = square();
+        let candidates = delaunay(&points);
+
+        assert_eq!(
+            sorted(relative_neighborhood(&points, &candidates, 1.0)),
+            vec![(0, 1), (0, 3), (1, 2), (2, 3)]
+        );
+    }
+
+    #[test]
+    fn nearest_neighbors_links_the_closest_points() {
+        let points: Vec<Vec2> = [0.0, 1.0, 3.0, 6.0]
+            .into_iter()
+            .map(|x| Vec2::new(x, 0.0))
+            .collect();
+
+        assert_eq!(
+            sorted(nearest_neighbors(&points, 1, 10.0)),
+            vec![(0, 1), (1, 2), (2, 3)]
+        );
+        // -- The last point has no neighbor within reach
+        assert_eq!(sorted(nearest_neighbors(&points, 1, 2.5)), vec![(0, 1), (1, 2)]);
+        // -- Every pair is linked once, however many ends picked it
+        assert_eq!(nearest_neighbors(&points, 3, 10.0).len(), 6);
+    }
+}
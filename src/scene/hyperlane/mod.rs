@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+
+use crate::scene::hyperlane::resources::*;
+use crate::scene::hyperlane::systems::*;
+
+pub mod graph;
+pub mod resources;
+pub mod systems;
+
+pub struct HyperlanePlugin;
+
+impl Plugin for HyperlanePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HyperlaneSettings>()
+            .init_resource::<Hyperlanes>()
+            .add_systems(
+                Update,
+                (
+                    control_hyperlanes,
+                    build_hyperlanes.run_if(hyperlanes_outdated),
+                    draw_hyperlanes.run_if(hyperlanes_enabled),
+                )
+                    .chain(),
+            );
+    }
+}
@@ -0,0 +1,65 @@
+use bevy::prelude::*;
+
+use crate::scene::hyperlane::graph::Edge;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GraphMethod {
+    /// Delaunay triangulation without edges above the length limit
+    Delaunay,
+    /// Relative neighborhood graph, sparser and without crossings
+    RelativeNeighborhood,
+    /// Every star linked to its closest neighbors
+    NearestNeighbors,
+}
+
+impl GraphMethod {
+    pub fn next(self) -> Self {
+        match self {
+            GraphMethod::Delaunay => GraphMethod::RelativeNeighborhood,
+            GraphMethod::RelativeNeighborhood => GraphMethod::NearestNeighbors,
+            GraphMethod::NearestNeighbors => GraphMethod::Delaunay,
+        }
+    }
+}
+
+#[derive(Resource, Clone)]
+pub struct HyperlaneSettings {
+    pub enabled: bool,
+    pub method: GraphMethod,
+    /// Longest allowed hyperlane, in scene units
+    pub max_edge_length: f32,
+    /// Neighbors per star for `GraphMethod::NearestNeighbors`
+    pub neighbors: usize,
+}
+
+impl Default for HyperlaneSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            method: GraphMethod::Delaunay,
+            max_edge_length: 2.0,
+            neighbors: 3,
+        }
+    }
+}
+
+/// Jump-route network between stars. Edges index into `nodes`.
+#[derive(Resource, Default)]
+pub struct Hyperlanes {
+    pub nodes: Vec<Entity>,
+    pub edges: Vec<Edge>,
+}
+
+impl Hyperlanes {
+    /// Neighbors of every node, by node index
+    pub fn adjacency(&self) -> Vec<Vec<usize>> {
+        let mut adjacency = vec![Vec::new(); self.nodes.len()];
+
+        for &(a, b) in &self.edges {
+            adjacency[a].push(b);
+            adjacency[b].push(a);
+        }
+
+        adjacency
+    }
+}
@@ -0,0 +1,190 @@
+use std::fs;
+
+use bevy::prelude::*;
+use serde::Serialize;
+
+use crate::configuration::resources::Configuration;
+use crate::scene::catalog::CatalogEntry;
+use crate::scene::hyperlane::graph::*;
+use crate::scene::hyperlane::resources::*;
+use crate::scene::orbiting_body::events::BodiesReshaped;
+use crate::scene::sector::resources::SectorSettings;
+use crate::scene::*;
+
+const HYPERLANE_TOGGLE_KEY: KeyCode = KeyCode::KeyH;
+const HYPERLANE_METHOD_KEY: KeyCode = KeyCode::KeyN;
+const HYPERLANE_EXPORT_KEY: KeyCode = KeyCode::KeyX;
+const HYPERLANE_EXPORT_PATH: &str = "hyperlanes.json";
+const HYPERLANE_COLOR: Color = Color::srgba(0.3, 0.7, 1.0, 0.5);
+
+pub fn hyperlanes_enabled(settings: Res<HyperlaneSettings>) -> bool {
+    settings.enabled
+}
+
+/// The network is rebuilt when its settings change or the galaxy is regenerated or reshaped
+pub fn hyperlanes_outdated(
+    settings: Res<HyperlaneSettings>,
+    added_query: Query<(), Added<OrbitingBody>>,
+    mut evr_reshaped: EventReader<BodiesReshaped>,
+) -> bool {
+    // -- Read even while disabled, an old reshape mustn't rebuild on enabling
+    let reshaped = evr_reshaped.read().count() > 0;
+
+    settings.enabled && (settings.is_changed() || !added_query.is_empty() || reshaped)
+}
+
+pub fn control_hyperlanes(
+    kbd: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<HyperlaneSettings>,
+    hyperlanes: Res<Hyperlanes>,
+    sectors: Res<SectorSettings>,
+    configuration: Res<Configuration>,
+    q_bodies: Query<(&GlobalTransform, &BodyKind, &CatalogEntry)>,
+) {
+    if kbd.just_pressed(HYPERLANE_TOGGLE_KEY) {
+        settings.enabled = !settings.enabled;
+    }
+    if kbd.just_pressed(HYPERLANE_METHOD_KEY) && settings.enabled {
+        settings.method = settings.method.next();
+        info!("Hyperlanes: {:?}", settings.method);
+    }
+    if kbd.just_pressed(HYPERLANE_EXPORT_KEY) && settings.enabled {
+        let sector_name = |position: Vec3| {
+            sectors
+                .sector_at(position, configuration.galaxy_radius())
+                .map(|sector| sector.name)
+        };
+        export_hyperlanes(&settings, &hyperlanes, &q_bodies, sector_name);
+    }
+}
+
+pub fn build_hyperlanes(
+    settings: Res<HyperlaneSettings>,
+    mut hyperlanes: ResMut<Hyperlanes>,
+    q_bodies: Query<(Entity, &OrbitingBody, &BodyKind)>,
+) {
+    let (nodes, points): (Vec<Entity>, Vec<Vec2>) = q_bodies
+        .iter()
+        .filter(|(_, _, kind)| **kind == BodyKind::Star)
+        .map(|(entity, body, _)| (entity, body.position().xz()))
+        .unzip();
+
+    let max_length = settings.max_edge_length;
+    let within_limit = |&(a, b): &Edge| points[a].distance(points[b]) <= max_length;
+
+    let edges = match settings.method {
+        GraphMethod::Delaunay => delaunay(&points).into_iter().filter(within_limit).collect(),
+        GraphMethod::RelativeNeighborhood => {
+            let candidates: Vec<Edge> =
+                delaunay(&points).into_iter().filter(within_limit).collect();
+
+            relative_neighborhood(&points, &candidates, max_length / 2.0)
+        }
+        GraphMethod::NearestNeighbors => nearest_neighbors(&points, settings.neighbors, max_length),
+    };
+
+    hyperlanes.nodes = nodes;
+    hyperlanes.edges = edges;
+}
+
+pub fn draw_hyperlanes(
+    hyperlanes: Res<Hyperlanes>,
+    q_bodies: Query<&GlobalTransform>,
+    mut gizmos: Gizmos,
+) {
+    // -- Lanes follow the stars as they orbit
+    let positions: Vec<Option<Vec3>> = hyperlanes
+        .nodes
+        .iter()
+        .map(|entity| q_bodies.get(*entity).ok().map(GlobalTransform::translation))
+        .collect();
+
+    for &(a, b) in &hyperlanes.edges {
+        if let (Some(start), Some(end)) = (positions[a], positions[b]) {
+            gizmos.line(start, end, HYPERLANE_COLOR);
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct HyperlaneExport {
+    method: String,
+    nodes: Vec<NodeExport>,
+    edges: Vec<EdgeExport>,
+}
+
+#[derive(Serialize)]
+struct NodeExport {
+    id: usize,
+    name: String,
+    kind: String,
+    x: f32,
+    z: f32,
+    sector: Option<String>,
+}
+
+#[derive(Serialize)]
+struct EdgeExport {
+    from: usize,
+    to: usize,
+    length: f32,
+}
+
+fn export_hyperlanes(
+    settings: &HyperlaneSettings,
+    hyperlanes: &Hyperlanes,
+    q_bodies: &Query<(&GlobalTransform, &BodyKind, &CatalogEntry)>,
+    sector_name: impl Fn(Vec3) -> Option<String>,
+) {
+    let positions: Vec<Vec2> = hyperlanes
+        .nodes
+        .iter()
+        .map(|entity| {
+            q_bodies
+                .get(*entity)
+                .map(|(transform, _, _)| transform.translation().xz())
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let export = HyperlaneExport {
+        method: format!("{:?}", settings.method),
+        nodes: positions
+            .iter()
+            .enumerate()
+            .map(|(id, position)| {
+                let (name, kind) = q_bodies
+                    .get(hyperlanes.nodes[id])
+                    .map(|(_, kind, entry)| (entry.label(), kind.to_string()))
+                    .unwrap_or_default();
+
+                NodeExport {
+                    id,
+                    name,
+                    kind,
+                    x: position.x,
+                    z: position.y,
+                    sector: sector_name(Vec3::new(position.x, 0.0, position.y)),
+                }
+            })
+            .collect(),
+        edges: hyperlanes
+            .edges
+            .iter()
+            .map(|&(from, to)| EdgeExport {
+                from,
+                to,
+                length: positions[from].distance(positions[to]),
+            })
+            .collect(),
+    };
+
+    let result = serde_json::to_string_pretty(&export)
+        .map_err(|error| error.to_string())
+        .and_then(|json| fs::write(HYPERLANE_EXPORT_PATH, json).map_err(|error| error.to_string()));
+
+    match result {
+        Ok(()) => info!("Hyperlanes exported to {}", HYPERLANE_EXPORT_PATH),
+        Err(error) => error!("Failed to export hyperlanes: {}", error),
+    }
+}
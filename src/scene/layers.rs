@@ -0,0 +1,76 @@
+use bevy::prelude::*;
+
+use crate::scene::center_body::CenterBody;
+use crate::scene::orbiting_body::components::{BodyKind, OrbitingBody};
+
+/// Which kinds of bodies are shown. Unlike the generation settings
+/// in `Configuration`, hiding a layer keeps the galaxy as it is.
+#[derive(Resource)]
+pub struct LayerVisibility {
+    pub stars: bool,
+    pub dust: bool,
+    pub hii: bool,
+    pub filaments: bool,
+    pub center: bool,
+}
+
+impl Default for LayerVisibility {
+    fn default() -> Self {
+        Self {
+            stars: true,
+            dust: true,
+            hii: true,
+            filaments: true,
+            center: true,
+        }
+    }
+}
+
+impl LayerVisibility {
+    pub fn shows(&self, kind: BodyKind) -> bool {
+        match kind {
+            BodyKind::Star => self.stars,
+            BodyKind::Dust => self.dust,
+            BodyKind::HII => self.hii,
+            BodyKind::Filament => self.filaments,
+        }
+    }
+}
+
+pub struct LayersPlugin;
+
+impl Plugin for LayersPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LayerVisibility>()
+            .add_systems(Update, apply_layer_visibility.run_if(layers_outdated));
+    }
+}
+
+/// Layers are applied again when toggled or when the galaxy is regenerated
+pub fn layers_outdated(
+    layers: Res<LayerVisibility>,
+    added_query: Query<(), Added<OrbitingBody>>,
+) -> bool {
+    layers.is_changed() || !added_query.is_empty()
+}
+
+pub fn apply_layer_visibility(
+    layers: Res<LayerVisibility>,
+    mut q_bodies: Query<(&BodyKind, &mut Visibility)>,
+    mut q_center: Query<&mut Visibility, (With<CenterBody>, Without<BodyKind>)>,
+) {
+    for (kind, mut visibility) in &mut q_bodies {
+        visibility.set_if_neq(layer_visibility(layers.shows(*kind)));
+    }
+    for mut visibility in &mut q_center {
+        visibility.set_if_neq(layer_visibility(layers.center));
+    }
+}
+
+fn layer_visibility(shown: bool) -> Visibility {
+    if shown {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    }
+}
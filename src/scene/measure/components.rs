@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct MeasurePanel;
+
+#[derive(Component)]
+pub struct MeasureText;
@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+use crate::scene::measure::resources::Measurement;
+use crate::scene::measure::systems::*;
+use crate::scene::picking::ActiveTool;
+
+pub mod components;
+pub mod resources;
+pub mod systems;
+
+pub struct MeasurePlugin;
+
+impl Plugin for MeasurePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Measurement>()
+            .add_systems(OnEnter(ActiveTool::Measure), spawn_measure_panel)
+            .add_systems(OnExit(ActiveTool::Measure), despawn_measure_panel)
+            .add_systems(
+                Update,
+                (
+                    toggle_measure_tool,
+                    switch_distance_unit,
+                    (pick_measured_bodies, draw_measurement, update_measure_panel)
+                        .chain()
+                        .run_if(in_state(ActiveTool::Measure)),
+                ),
+            );
+    }
+}
@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+/// Bodies picked with the measure tool, at most two
+#[derive(Resource, Default)]
+pub struct Measurement {
+    pub bodies: Vec<Entity>,
+}
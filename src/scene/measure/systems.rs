@@ -0,0 +1,146 @@
+use bevy::prelude::*;
+
+use crate::configuration::resources::MapUnits;
+use crate::scene::catalog::CatalogEntry;
+use crate::scene::measure::components::*;
+use crate::scene::measure::resources::Measurement;
+use crate::scene::picking::{ActiveTool, BodyClicked};
+use crate::scene::sector::resources::{galactic_angle, galactocentric_radius};
+use crate::scene::{BodyKind, OrbitingBody};
+use crate::ui::styles::*;
+
+const MEASURE_TOOL_KEY: KeyCode = KeyCode::KeyT;
+const DISTANCE_UNIT_KEY: KeyCode = KeyCode::KeyU;
+const MEASURE_COLOR: Color = Color::srgb(0.2, 1.0, 0.6);
+
+pub fn toggle_measure_tool(
+    kbd: Res<ButtonInput<KeyCode>>,
+    tool: Res<State<ActiveTool>>,
+    mut next_tool: ResMut<NextState<ActiveTool>>,
+    mut measurement: ResMut<Measurement>,
+) {
+    if kbd.just_pressed(MEASURE_TOOL_KEY) {
+        measurement.bodies.clear();
+        next_tool.set(match tool.get() {
+            ActiveTool::Measure => ActiveTool::None,
+            _ => ActiveTool::Measure,
+        });
+    }
+}
+
+pub fn switch_distance_unit(kbd: Res<ButtonInput<KeyCode>>, mut units: ResMut<MapUnits>) {
+    if kbd.just_pressed(DISTANCE_UNIT_KEY) {
+        units.unit = units.unit.next();
+    }
+}
+
+pub fn pick_measured_bodies(
+    mut evr_clicked: EventReader<BodyClicked>,
+    mut measurement: ResMut<Measurement>,
+) {
+    for ev in evr_clicked.read() {
+        // -- A third click starts a new measurement
+        if measurement.bodies.len() == 2 {
+            measurement.bodies.clear();
+        }
+        measurement.bodies.push(ev.entity);
+    }
+}
+
+pub fn draw_measurement(
+    mut measurement: ResMut<Measurement>,
+    q_bodies: Query<&GlobalTransform, With<OrbitingBody>>,
+    mut gizmos: Gizmos,
+) {
+    // -- Bodies are gone after the galaxy is regenerated
+    if measurement
+        .bodies
+        .iter()
+        .any(|entity| !q_bodies.contains(*entity))
+    {
+        measurement.bodies.clear();
+    }
+
+    let positions: Vec<Vec3> = q_bodies
+        .iter_many(&measurement.bodies)
+        .map(GlobalTransform::translation)
+        .collect();
+
+    for position in &positions {
+        gizmos.sphere(*position, Quat::IDENTITY, 0.1, MEASURE_COLOR);
+    }
+    if let [start, end] = positions[..] {
+        gizmos.line(start, end, MEASURE_COLOR);
+    }
+}
+
+pub fn spawn_measure_panel(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let wrapper = NodeBundle {
+        style: TOOL_PANEL_STYLE,
+        background_color: UI_BACKGROUND_COLOR,
+        border_radius: UI_BORDER_RADIUS,
+        ..default()
+    };
+    let text = TextBundle {
+        text: Text::from_section("", get_text_style(&asset_server, 16.0)),
+        ..default()
+    };
+
+    commands
+        .spawn((wrapper, MeasurePanel))
+        .with_children(|parent| {
+            parent.spawn((text, MeasureText));
+        });
+}
+
+pub fn despawn_measure_panel(
+    mut commands: Commands,
+    panel_query: Query<Entity, With<MeasurePanel>>,
+) {
+    for entity in panel_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn update_measure_panel(
+    measurement: Res<Measurement>,
+    units: Res<MapUnits>,
+    q_bodies: Query<&GlobalTransform, With<OrbitingBody>>,
+    q_catalog: Query<(&BodyKind, &CatalogEntry)>,
+    mut text_query: Query<&mut Text, With<MeasureText>>,
+) {
+    let names: Vec<String> = q_catalog
+        .iter_many(&measurement.bodies)
+        .map(|(kind, entry)| entry.describe(*kind))
+        .collect();
+    let positions: Vec<Vec3> = q_bodies
+        .iter_many(&measurement.bodies)
+        .map(GlobalTransform::translation)
+        .collect();
+
+    let value = match (&positions[..], &names[..]) {
+        (&[start, end], [from, to]) => {
+            let distance = start.distance(end);
+            let radius_difference =
+                (galactocentric_radius(end) - galactocentric_radius(start)).abs();
+            let angle_difference = (galactic_angle(end) - galactic_angle(start)).to_degrees();
+            // -- The shorter way around the center
+            let separation = (angle_difference + 180.0).rem_euclid(360.0) - 180.0;
+
+            format!(
+                "From: {}\nTo: {}\nDistance: {}\nRadius difference: {}\nAngular separation: {:.1}°",
+                from,
+                to,
+                units.format(distance),
+                units.format(radius_difference),
+                separation.abs(),
+            )
+        }
+        (_, [from]) => format!("From: {}\nClick a second body", from),
+        _ => String::from("Click two bodies to measure"),
+    };
+
+    for mut text in &mut text_query {
+        text.sections[0].value = value.clone();
+    }
+}
@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+use bevy::render::view::RenderLayers;
+
+/// Layers drawn only by the minimap camera, so its overlay stays off the main view
+pub const MINIMAP_LAYERS: RenderLayers = RenderLayers::layer(1);
+
+#[derive(Component)]
+pub struct Minimap;
+
+#[derive(Default, Reflect, GizmoConfigGroup)]
+pub struct MinimapGizmos;
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+use crate::configuration::resources::Configuration;
+use crate::scene::minimap::components::*;
+use crate::scene::orbiting_body::events::BodiesReshaped;
+use crate::scene::minimap::systems::*;
+
+pub mod components;
+pub mod systems;
+
+pub struct MinimapPlugin;
+
+impl Plugin for MinimapPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_gizmo_config(
+            MinimapGizmos,
+            GizmoConfig {
+                render_layers: MINIMAP_LAYERS,
+                // -- Keep the indicator on top of the bodies
+                depth_bias: -1.0,
+                ..default()
+            },
+        )
+        .add_systems(Startup, spawn_minimap)
+        .add_systems(
+            Update,
+            (
+                place_minimap,
+                draw_view_indicator,
+                recenter_on_minimap_click,
+                // -- Playing the timeline reshapes the galaxy without changing the configuration
+                fit_minimap_to_galaxy.run_if(
+                    on_event::<BodiesReshaped>().or_else(resource_changed::<Configuration>),
+                ),
+            ),
+        );
+    }
+}
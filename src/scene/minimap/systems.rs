@@ -0,0 +1,174 @@
+use bevy::prelude::*;
+use bevy::render::camera::{ScalingMode, Viewport};
+use bevy::render::view::RenderLayers;
+use bevy::window::PrimaryWindow;
+
+use crate::configuration::resources::Configuration;
+use crate::scene::camera::components::PanOrbitState;
+use crate::scene::minimap::components::*;
+
+/// Side of the minimap as a fraction of the window height
+const MINIMAP_SIZE: f32 = 0.25;
+/// Gap between the minimap and the window edges, in logical pixels
+const MINIMAP_MARGIN: f32 = 16.0;
+const MINIMAP_BACKGROUND_COLOR: Color = Color::srgb(0.03, 0.03, 0.06);
+const VIEW_INDICATOR_COLOR: Color = Color::srgb(1.0, 0.8, 0.2);
+
+pub fn spawn_minimap(mut commands: Commands, configuration: Res<Configuration>) {
+    let camera = Camera3dBundle {
+        camera: Camera {
+            // -- Drawn after the main camera, on top of it
+            order: 1,
+            clear_color: ClearColorConfig::Custom(MINIMAP_BACKGROUND_COLOR),
+            ..default()
+        },
+        projection: Projection::Orthographic(OrthographicProjection {
+            scaling_mode: ScalingMode::FixedVertical(minimap_extent(&configuration)),
+            ..default()
+        }),
+        // -- Looking straight down with -Z up, matching the default main camera heading
+        transform: Transform::from_xyz(0.0, 100.0, 0.0).looking_at(Vec3::ZERO, Vec3::NEG_Z),
+        ..default()
+    };
+
+    commands.spawn((
+        camera,
+        Minimap,
+        RenderLayers::layer(0).union(&MINIMAP_LAYERS),
+    ));
+}
+
+pub fn fit_minimap_to_galaxy(
+    configuration: Res<Configuration>,
+    mut q_minimap: Query<&mut Projection, With<Minimap>>,
+) {
+    for mut projection in &mut q_minimap {
+        if let Projection::Orthographic(ortho) = projection.as_mut() {
+            ortho.scaling_mode = ScalingMode::FixedVertical(minimap_extent(&configuration));
+        }
+    }
+}
+
+pub fn place_minimap(
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    mut q_minimap: Query<&mut Camera, With<Minimap>>,
+) {
+    let Ok(window) = q_window.get_single() else {
+        return;
+    };
+
+    let scale = window.scale_factor();
+    let size = (window.physical_height() as f32 * MINIMAP_SIZE) as u32;
+    let margin = (MINIMAP_MARGIN * scale) as u32;
+    let viewport = Viewport {
+        physical_position: UVec2::new(
+            window.physical_width().saturating_sub(size + margin),
+            window.physical_height().saturating_sub(size + margin),
+        ),
+        physical_size: UVec2::splat(size.max(1)),
+        ..default()
+    };
+
+    for mut camera in &mut q_minimap {
+        let unchanged = camera.viewport.as_ref().is_some_and(|current| {
+            current.physical_position == viewport.physical_position
+                && current.physical_size == viewport.physical_size
+        });
+
+        if !unchanged {
+            camera.viewport = Some(viewport.clone());
+        }
+    }
+}
+
+pub fn draw_view_indicator(
+    configuration: Res<Configuration>,
+    q_main: Query<(&Camera, &GlobalTransform, &PanOrbitState)>,
+    mut gizmos: Gizmos<MinimapGizmos>,
+) {
+    let max_distance = minimap_extent(&configuration);
+
+    for (camera, camera_transform, state) in &q_main {
+        let Some(size) = camera.logical_viewport_size() else {
+            continue;
+        };
+
+        // -- Where the corners of the main view land on the galactic plane
+        let corners = [
+            Vec2::ZERO,
+            Vec2::new(size.x, 0.0),
+            size,
+            Vec2::new(0.0, size.y),
+            Vec2::ZERO,
+        ];
+        let footprint = corners.iter().filter_map(|corner| {
+            camera
+                .viewport_to_world(camera_transform, *corner)
+                .map(|ray| ground_point(ray, max_distance))
+        });
+
+        gizmos.linestrip(footprint, VIEW_INDICATOR_COLOR);
+
+        // -- Line of sight from the camera to the point it orbits
+        let eye = camera_transform.translation().with_y(0.0);
+        let center = state.center.with_y(0.0);
+
+        gizmos.line(eye, center, VIEW_INDICATOR_COLOR);
+        gizmos.circle(center, Dir3::Y, max_distance * 0.02, VIEW_INDICATOR_COLOR);
+    }
+}
+
+pub fn recenter_on_minimap_click(
+    ms: Res<ButtonInput<MouseButton>>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    q_minimap: Query<(&Camera, &GlobalTransform), With<Minimap>>,
+    mut q_main: Query<(&mut PanOrbitState, &mut Transform)>,
+    configuration: Res<Configuration>,
+) {
+    if !ms.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    let Some(cursor) = q_window.get_single().ok().and_then(Window::cursor_position) else {
+        return;
+    };
+
+    for (camera, camera_transform) in &q_minimap {
+        let Some(rect) = camera.logical_viewport_rect() else {
+            continue;
+        };
+        if !rect.contains(cursor) {
+            continue;
+        }
+
+        let Some(ray) = camera.viewport_to_world(camera_transform, cursor - rect.min) else {
+            continue;
+        };
+        let target = ground_point(ray, minimap_extent(&configuration));
+
+        // -- Move the whole camera, so the view keeps its angle and distance
+        for (mut state, mut transform) in &mut q_main {
+            let offset = (target - state.center).with_y(0.0);
+
+            state.center += offset;
+            state.pan_velocity = Vec2::ZERO;
+            transform.translation += offset;
+        }
+    }
+}
+
+/// Size of the area shown by the minimap, with some room around the outermost orbit
+fn minimap_extent(configuration: &Configuration) -> f32 {
+    configuration.galaxy_radius() * 2.2
+}
+
+/// Where the ray hits the galactic plane, or how far it gets
+/// toward it when it points away or hits too far off
+fn ground_point(ray: Ray3d, max_distance: f32) -> Vec3 {
+    let distance = ray
+        .intersect_plane(Vec3::ZERO, InfinitePlane3d::new(Vec3::Y))
+        .filter(|distance| *distance < max_distance)
+        .unwrap_or(max_distance);
+
+    ray.get_point(distance).with_y(0.0)
+}
@@ -2,13 +2,43 @@ use bevy::prelude::*;
 
 use camera::CameraPlugin;
 use fps::FpsPlugin;
+use grid::GridPlugin;
+use heatmap::HeatmapPlugin;
+use hyperlane::HyperlanePlugin;
+use layers::LayersPlugin;
+use measure::MeasurePlugin;
+use minimap::MinimapPlugin;
+use picking::PickingPlugin;
+use route::RoutePlugin;
+use search::SearchPlugin;
+use sector::SectorPlugin;
+use stats::StatsPlugin;
+use selection::SelectionPlugin;
+use territory::TerritoryPlugin;
+use timeline::TimelinePlugin;
 use orbiting_body::OrbitingBodyPlugin;
 use center_body::CenterBodyPlugin;
 
 pub mod camera;
+pub mod catalog;
 pub mod center_body;
 pub mod fps;
+pub mod grid;
+pub mod heatmap;
+pub mod hyperlane;
+pub mod layers;
+pub mod measure;
+pub mod minimap;
 pub mod orbiting_body;
+pub mod picking;
+pub mod plane_map;
+pub mod route;
+pub mod search;
+pub mod sector;
+pub mod selection;
+pub mod stats;
+pub mod territory;
+pub mod timeline;
 
 pub use orbiting_body::components::*;
 
@@ -21,6 +51,23 @@ impl Plugin for ScenePlugin {
             CameraPlugin,
             OrbitingBodyPlugin,
             CenterBodyPlugin,
+            LayersPlugin,
+            MinimapPlugin,
+            PickingPlugin,
+            SelectionPlugin,
+            SearchPlugin,
+            StatsPlugin,
+            TimelinePlugin,
+        ))
+        // -- Overlays and tools
+        .add_plugins((
+            GridPlugin,
+            MeasurePlugin,
+            HyperlanePlugin,
+            RoutePlugin,
+            TerritoryPlugin,
+            SectorPlugin,
+            HeatmapPlugin,
         ))
         .insert_resource(ClearColor(Color::BLACK));
     }
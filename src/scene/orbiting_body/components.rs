@@ -9,15 +9,81 @@ pub struct OrbitingBody {
     pub orbit_speed: f32,
     pub semi_axis: SemiAxis,
     pub y_rotation: Quat,
+    /// Brightness of the body's orbit, from 1.0 at the center down
+    pub dimming_channel: f32,
+    /// Index of the body's orbit, counted from the center
+    pub orbit: u32,
+    /// Random factors applied to the orbit's semi-axis and speed,
+    /// kept so that the orbit can be reshaped without respawning
+    pub semi_axis_spread: f32,
+    pub speed_fluctuation: f32,
 }
 
-#[derive(Debug, Clone, Copy)]
+impl OrbitingBody {
+    /// Where the body is on its orbit. Unlike `GlobalTransform` it's
+    /// right from the frame the body is spawned in.
+    pub fn position(&self) -> Vec3 {
+        let x = self.semi_axis.major * self.angle.cos();
+        let z = self.semi_axis.minor * self.angle.sin();
+
+        // -- Position in local orbit coordinates (X-Z plane), rotated only around Y
+        self.y_rotation * Vec3::new(x, 0.0, z)
+    }
+}
+
+/// What a body is, set when it's spawned
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BodyKind {
+    Star,
+    Dust,
+    HII,
+    Filament,
+}
+
+impl BodyKind {
+    pub const ALL: [BodyKind; 4] = [
+        BodyKind::Star,
+        BodyKind::Dust,
+        BodyKind::HII,
+        BodyKind::Filament,
+    ];
+}
+
+impl Display for BodyKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            BodyKind::Star => "Star",
+            BodyKind::Dust => "Dust cloud",
+            BodyKind::HII => "HII region",
+            BodyKind::Filament => "Filament",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct SemiAxis {
     pub major: f32,
     pub minor: f32,
 }
 
 impl SemiAxis {
+    /// Same shape with the given semi-major axis
+    pub fn with_major(self, major: f32) -> Self {
+        // -- Rescaling by a ratio of one would still round the minor semi-axis
+        if major == self.major {
+            return self;
+        }
+        // -- A collapsed axis has no proportion left to keep, the default one is used
+        let shape = if self.major > 0.0 { self } else { Self::default() };
+
+        Self {
+            major,
+            minor: shape.minor * major / shape.major,
+        }
+    }
+
     fn get_scale(&self, new_major: f32) -> f32 {
         new_major / self.major
     }
@@ -0,0 +1,5 @@
+use bevy::prelude::*;
+
+/// The bodies took a new shape in place, without being spawned again
+#[derive(Event)]
+pub struct BodiesReshaped;
@@ -1,9 +1,11 @@
 use bevy::prelude::*;
 
 use crate::configuration::resources::Configuration;
+use events::*;
 use systems::*;
 
 pub mod components;
+pub mod events;
 pub mod systems;
 
 pub struct OrbitingBodyPlugin;
@@ -11,17 +13,17 @@ pub struct OrbitingBodyPlugin;
 
 impl Plugin for OrbitingBodyPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, spawn_bodies).add_systems(
-            Update,
-            (
-                orbit_bodies,
-                // -- Note --
-                // Not a great solution, can be vastly improved
-                // by de-spawning and changing certain entities
-                // Omitting for now
-                respawn_bodies
-                    .run_if(resource_changed::<Configuration>),
-            ),
-        );
+        app.add_event::<BodiesReshaped>()
+            .add_systems(Startup, spawn_bodies)
+            .add_systems(
+                Update,
+                (
+                    orbit_bodies,
+                    // -- Continuous parameters reshape the bodies in place,
+                    // any other change regenerates all of them
+                    respawn_bodies
+                        .run_if(resource_changed::<Configuration>),
+                ),
+            );
     }
 }
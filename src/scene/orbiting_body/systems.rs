@@ -3,7 +3,10 @@ use std::f32::consts::PI;
 use bevy::prelude::*;
 use rand::prelude::*;
 
+use crate::configuration::parameters::same_bodies;
 use crate::configuration::resources::*;
+use crate::scene::catalog::CatalogEntry;
+use crate::scene::orbiting_body::events::BodiesReshaped;
 use crate::scene::*;
 
 const ANGLE_STEP: f32 = PI / 16.0;
@@ -14,38 +17,35 @@ pub fn spawn_bodies(
     mut materials: ResMut<Assets<StandardMaterial>>,
     configuration: Res<Configuration>,
 ) {
+    // -- Every way of changing the configuration validates it first, this is a last resort
+    if let Err(errors) = configuration.validate() {
+        error!("Not generating the galaxy, invalid configuration: {}", errors);
+        return;
+    }
+
     let Configuration {
-        star_count,
         dust,
         h2,
         filament,
-        velocity,
-        semi_axis,
         stars_per_orbit,
         dimming_speed,
-        orbit_density,
-        orbit_rotation,
         star_size,
+        seed,
+        ..
     } = *configuration;
 
-    let mut rng = thread_rng();
+    let mut rng = StdRng::seed_from_u64(seed);
 
-    let num_orbits = star_count / stars_per_orbit as u32;
+    let num_orbits = configuration.orbit_count();
 
     for i in 0..num_orbits {
-        // -- Stars orbits rotate and grow farther from the center
-        let orbit_density = i as f32 / orbit_density;
-        let semi_axis = semi_axis + orbit_density * semi_axis;
-        let angle_offset = i as f32 * orbit_rotation;
+        let shape = OrbitShape::new(&configuration, i);
 
         // -- Stars dimming farther from the center
         let dimming_step = dimming_speed / num_orbits as f32;
         let step_percent = dimming_step / num_orbits as f32;
         let dimming_channel = 1.0 - i as f32 * step_percent;
 
-        // -- Stars speed decreasing farther from the center
-        let orbit_speed = (velocity / (i as f32 + 1.0)) * 3.0;
-
         // -- Initialize meshes
         let star_mesh = get_star_mesh(&mut meshes, &mut materials, dimming_channel, star_size);
         let filament_mesh =
@@ -55,17 +55,24 @@ pub fn spawn_bodies(
 
         for j in 0..stars_per_orbit {
             // -- Stars are distributed uniformly in the orbit
-            let angle = j as f32 * ANGLE_STEP + angle_offset;
+            let angle = j as f32 * ANGLE_STEP + shape.angle_offset;
 
             // -- Speed and semi-axis are slightly altered
-            let semi_axis = semi_axis * rng.gen_range(0.9..1.1);
+            let semi_axis_spread = rng.gen_range(0.9..1.1);
             let speed_fluctuation = rng.gen_range(0.01..0.1);
 
+            // -- Every slot of every orbit holds exactly one body
+            let id = i * stars_per_orbit as u32 + j as u32;
+
             let orbiting_body = OrbitingBody {
                 angle,
-                semi_axis,
-                orbit_speed: orbit_speed + speed_fluctuation,
-                y_rotation: Quat::from_rotation_y(angle_offset),
+                semi_axis: shape.semi_axis * semi_axis_spread,
+                orbit_speed: shape.orbit_speed + speed_fluctuation,
+                y_rotation: Quat::from_rotation_y(shape.angle_offset),
+                dimming_channel,
+                orbit: i,
+                semi_axis_spread,
+                speed_fluctuation,
             };
 
             let mut spawn_star = true;
@@ -74,7 +81,12 @@ pub fn spawn_bodies(
 
             // -- Spawn filaments
             if filament && j % 4 == 0 {
-                commands.spawn((filament_mesh.clone(), orbiting_body.clone()));
+                commands.spawn((
+                    filament_mesh.clone(),
+                    orbiting_body.clone(),
+                    BodyKind::Filament,
+                    CatalogEntry::new(id, BodyKind::Filament),
+                ));
 
                 spawn_star = false;
                 spawn_dust = false;
@@ -83,7 +95,12 @@ pub fn spawn_bodies(
 
             // -- Spawn HII regions
             if h2 && spawn_h2 && j % 30 == 0 {
-                commands.spawn((h2_mesh.clone(), orbiting_body.clone()));
+                commands.spawn((
+                    h2_mesh.clone(),
+                    orbiting_body.clone(),
+                    BodyKind::HII,
+                    CatalogEntry::new(id, BodyKind::HII),
+                ));
 
                 spawn_star = false;
                 spawn_dust = false;
@@ -91,7 +108,12 @@ pub fn spawn_bodies(
 
             // -- Spawn dust
             if dust && spawn_dust && j % 5 == 0 {
-                commands.spawn((dust_mesh.clone(), orbiting_body.clone()));
+                commands.spawn((
+                    dust_mesh.clone(),
+                    orbiting_body.clone(),
+                    BodyKind::Dust,
+                    CatalogEntry::new(id, BodyKind::Dust),
+                ));
 
                 spawn_star = false;
             }
@@ -99,7 +121,12 @@ pub fn spawn_bodies(
 
             // -- Spawn a star
             if spawn_star {
-                commands.spawn((star_mesh.clone(), orbiting_body));
+                commands.spawn((
+                    star_mesh.clone(),
+                    orbiting_body,
+                    BodyKind::Star,
+                    CatalogEntry::new(id, BodyKind::Star),
+                ));
             }
         }
     }
@@ -108,33 +135,67 @@ pub fn spawn_bodies(
 pub fn respawn_bodies(
     mut commands: Commands,
     configuration: Res<Configuration>,
-    query: Query<Entity, With<OrbitingBody>>,
+    mut generated: Local<Option<Configuration>>,
+    mut query: Query<(Entity, &mut OrbitingBody)>,
     meshes: ResMut<Assets<Mesh>>,
     materials: ResMut<Assets<StandardMaterial>>,
+    mut evw_reshaped: EventWriter<BodiesReshaped>,
 ) {
-    if configuration.is_changed() {
-        for entity in query.iter() {
-            commands.entity(entity).despawn_recursive();
+    // -- Orbit shapes and speeds change on the bodies already there
+    if let Some(generated) = generated.as_ref() {
+        if same_bodies(generated, &configuration) {
+            for (_, mut body) in &mut query {
+                reshape_body(&mut body, &configuration);
+            }
+            evw_reshaped.send(BodiesReshaped);
+
+            return;
         }
+    }
+    *generated = Some(configuration.clone());
 
-        spawn_bodies(commands, meshes, materials, configuration);
+    for (entity, _) in query.iter() {
+        commands.entity(entity).despawn_recursive();
     }
+
+    spawn_bodies(commands, meshes, materials, configuration);
 }
 
-pub fn orbit_bodies(time: Res<Time>, mut query: Query<(&mut OrbitingBody, &mut Transform)>) {
-    for (mut orbiting_body, mut transform) in &mut query {
-        orbiting_body.angle += orbiting_body.orbit_speed * time.delta_seconds();
+/// Orbit of a body, before its random spread
+pub struct OrbitShape {
+    pub semi_axis: SemiAxis,
+    pub angle_offset: f32,
+    pub orbit_speed: f32,
+}
 
-        let x = orbiting_body.semi_axis.major * orbiting_body.angle.cos();
-        let z = orbiting_body.semi_axis.minor * orbiting_body.angle.sin();
+impl OrbitShape {
+    pub fn new(configuration: &Configuration, orbit: u32) -> Self {
+        // -- Stars orbits rotate and grow farther from the center
+        let orbit_density = orbit as f32 / configuration.orbit_density;
+        let semi_axis = configuration.semi_axis + orbit_density * configuration.semi_axis;
+
+        Self {
+            semi_axis,
+            angle_offset: orbit as f32 * configuration.orbit_rotation,
+            // -- Stars speed decreasing farther from the center
+            orbit_speed: (configuration.velocity / (orbit as f32 + 1.0)) * 3.0,
+        }
+    }
+}
 
-        // -- Position in local orbit coordinates (X-Z plane)
-        let local_position = Vec3::new(x, 0.0, z);
+/// Fits a body to a configuration generating the same bodies, see `same_bodies`
+pub fn reshape_body(body: &mut OrbitingBody, configuration: &Configuration) {
+    let shape = OrbitShape::new(configuration, body.orbit);
 
-        // -- Apply only the Y-axis rotation
-        let rotated_position = orbiting_body.y_rotation * local_position;
+    body.semi_axis = shape.semi_axis * body.semi_axis_spread;
+    body.orbit_speed = shape.orbit_speed + body.speed_fluctuation;
+    body.y_rotation = Quat::from_rotation_y(shape.angle_offset);
+}
 
-        transform.translation = rotated_position;
+pub fn orbit_bodies(time: Res<Time>, mut query: Query<(&mut OrbitingBody, &mut Transform)>) {
+    for (mut orbiting_body, mut transform) in &mut query {
+        orbiting_body.angle += orbiting_body.orbit_speed * time.delta_seconds();
+        transform.translation = orbiting_body.position();
     }
 }
 
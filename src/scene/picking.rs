@@ -0,0 +1,109 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::scene::camera::components::PanOrbitState;
+use crate::scene::minimap::components::Minimap;
+use crate::scene::orbiting_body::components::{BodyKind, OrbitingBody};
+
+/// Farthest a click can land from a body and still pick it, in logical pixels
+const PICK_RADIUS: f32 = 12.0;
+/// Cursor travel above which a press counts as a drag rather than a click
+const CLICK_TOLERANCE: f32 = 4.0;
+
+/// What clicking on a body does
+#[derive(States, Clone, Copy, Default, Eq, PartialEq, Hash, Debug)]
+pub enum ActiveTool {
+    #[default]
+    None,
+    Measure,
+    Route,
+}
+
+impl ActiveTool {
+    /// Whether clicks can pick bodies of this kind
+    pub fn picks(self, kind: BodyKind) -> bool {
+        match self {
+            // -- Routes run between stars, dust in front of one shouldn't get in the way
+            ActiveTool::Route => kind == BodyKind::Star,
+            _ => true,
+        }
+    }
+}
+
+#[derive(Event)]
+pub struct BodyClicked {
+    pub entity: Entity,
+    pub kind: BodyKind,
+}
+
+pub struct PickingPlugin;
+
+impl Plugin for PickingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<ActiveTool>()
+            .add_event::<BodyClicked>()
+            .add_systems(Update, click_bodies);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn click_bodies(
+    ms: Res<ButtonInput<MouseButton>>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    q_camera: Query<(&Camera, &GlobalTransform), With<PanOrbitState>>,
+    q_minimap: Query<&Camera, With<Minimap>>,
+    q_interaction: Query<&Interaction>,
+    q_bodies: Query<(Entity, &GlobalTransform, &InheritedVisibility, &BodyKind), With<OrbitingBody>>,
+    tool: Res<State<ActiveTool>>,
+    mut pressed_at: Local<Option<Vec2>>,
+    mut evw_clicked: EventWriter<BodyClicked>,
+) {
+    let Some(cursor) = q_window.get_single().ok().and_then(Window::cursor_position) else {
+        return;
+    };
+
+    // -- Presses on the UI or the minimap belong to them
+    if ms.just_pressed(MouseButton::Left) {
+        let over_ui = q_interaction
+            .iter()
+            .any(|interaction| *interaction != Interaction::None);
+        let over_minimap = q_minimap.iter().any(|camera| {
+            camera
+                .logical_viewport_rect()
+                .is_some_and(|rect| rect.contains(cursor))
+        });
+
+        *pressed_at = (!over_ui && !over_minimap).then_some(cursor);
+    }
+
+    if !ms.just_released(MouseButton::Left) {
+        return;
+    }
+
+    // -- The left button also pans, only a press without a drag is a click
+    let Some(start) = pressed_at.take() else {
+        return;
+    };
+    if start.distance(cursor) > CLICK_TOLERANCE {
+        return;
+    }
+
+    let Ok((camera, camera_transform)) = q_camera.get_single() else {
+        return;
+    };
+
+    let picked = q_bodies
+        .iter()
+        .filter(|(_, _, visibility, kind)| visibility.get() && tool.get().picks(**kind))
+        .filter_map(|(entity, transform, _, kind)| {
+            let position = camera.world_to_viewport(camera_transform, transform.translation())?;
+
+            Some((entity, *kind, position.distance(cursor)))
+        })
+        .filter(|(_, _, distance)| *distance <= PICK_RADIUS)
+        .min_by(|a, b| a.2.total_cmp(&b.2));
+
+    if let Some((entity, kind, _)) = picked {
+        evw_clicked.send(BodyClicked { entity, kind });
+    }
+}
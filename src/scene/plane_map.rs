@@ -0,0 +1,76 @@
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+/// A square texture laid flat on the galactic plane, for overlays
+/// painted pixel by pixel. The texture spans `-extent..extent` on X and Z.
+#[derive(Component)]
+pub struct PlaneMap {
+    pub image: Handle<Image>,
+    pub resolution: u32,
+    pub extent: f32,
+}
+
+impl PlaneMap {
+    /// Center of a pixel on the galactic plane, as (x, z)
+    pub fn pixel_position(&self, column: u32, row: u32) -> Vec2 {
+        let pixel_size = 2.0 * self.extent / self.resolution as f32;
+
+        Vec2::new(
+            -self.extent + (column as f32 + 0.5) * pixel_size,
+            -self.extent + (row as f32 + 0.5) * pixel_size,
+        )
+    }
+
+    /// Plane covering `-extent..extent`, `height` above the galactic plane
+    pub fn transform(&self, height: f32) -> Transform {
+        Transform::from_xyz(0.0, height, 0.0).with_scale(Vec3::new(self.extent, 1.0, self.extent))
+    }
+}
+
+/// Spawns a transparent plane map and returns its entity commands for tagging
+pub fn spawn_plane_map<'a>(
+    commands: &'a mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    images: &mut Assets<Image>,
+    resolution: u32,
+    extent: f32,
+    height: f32,
+) -> EntityCommands<'a> {
+    let image = images.add(Image::new_fill(
+        Extent3d {
+            width: resolution,
+            height: resolution,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    ));
+    let material = StandardMaterial {
+        base_color_texture: Some(image.clone()),
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        double_sided: true,
+        cull_mode: None,
+        ..default()
+    };
+    let map = PlaneMap {
+        image,
+        resolution,
+        extent,
+    };
+
+    // -- A 2x2 plane, scaled up to the extent by the transform
+    let bundle = PbrBundle {
+        mesh: meshes.add(Plane3d::default().mesh().size(2.0, 2.0)),
+        material: materials.add(material),
+        transform: map.transform(height),
+        ..default()
+    };
+
+    commands.spawn((bundle, map))
+}
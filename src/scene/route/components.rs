@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct RoutePanel;
+
+#[derive(Component)]
+pub struct RouteText;
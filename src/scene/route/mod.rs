@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+
+use crate::scene::picking::ActiveTool;
+use crate::scene::route::resources::*;
+use crate::scene::route::systems::*;
+
+pub mod components;
+pub mod path;
+pub mod resources;
+pub mod systems;
+
+pub struct RoutePlugin;
+
+impl Plugin for RoutePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RouteSettings>()
+            .init_resource::<RouteEnds>()
+            .init_resource::<Route>()
+            .add_systems(OnEnter(ActiveTool::Route), spawn_route_panel)
+            .add_systems(OnExit(ActiveTool::Route), despawn_route_panel)
+            .add_systems(
+                Update,
+                (
+                    toggle_route_tool,
+                    (
+                        switch_route_cost,
+                        pick_route_ends,
+                        plan_route.run_if(route_outdated),
+                        draw_route,
+                        update_route_panel,
+                    )
+                        .chain()
+                        .run_if(in_state(ActiveTool::Route)),
+                ),
+            );
+    }
+}
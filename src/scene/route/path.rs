@@ -0,0 +1,141 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Frontier entry, the lowest estimated total cost is popped first
+struct Candidate {
+    estimate: f32,
+    node: usize,
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.total_cmp(&self.estimate)
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+/// A* search over an adjacency list, returning the nodes from `start` to `goal`
+/// and the total cost. `heuristic` must never overestimate the remaining cost,
+/// a heuristic of zero makes it a plain Dijkstra search.
+pub fn shortest_path(
+    adjacency: &[Vec<usize>],
+    start: usize,
+    goal: usize,
+    cost: impl Fn(usize, usize) -> f32,
+    heuristic: impl Fn(usize) -> f32,
+) -> Option<(Vec<usize>, f32)> {
+    let mut best = vec![f32::INFINITY; adjacency.len()];
+    let mut previous = vec![None; adjacency.len()];
+    let mut frontier = BinaryHeap::new();
+
+    best[start] = 0.0;
+    frontier.push(Candidate {
+        estimate: heuristic(start),
+        node: start,
+    });
+
+    while let Some(Candidate { estimate, node }) = frontier.pop() {
+        if node == goal {
+            let mut path = vec![goal];
+            while let Some(step) = previous[*path.last()?] {
+                path.push(step);
+            }
+            path.reverse();
+
+            return Some((path, best[goal]));
+        }
+
+        // -- Skip entries left behind by a cheaper way to the same node
+        if estimate > best[node] + heuristic(node) {
+            continue;
+        }
+
+        for &next in &adjacency[node] {
+            let total = best[node] + cost(node, next);
+
+            if total < best[next] {
+                best[next] = total;
+                previous[next] = Some(node);
+                frontier.push(Candidate {
+                    estimate: total + heuristic(next),
+                    node: next,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A chain 0-1-2-3 of short lanes and a long lane straight from 0 to 3,
+    /// with 4 cut off from the rest
+    fn graph() -> (Vec<Vec<usize>>, impl Fn(usize, usize) -> f32) {
+        let adjacency = vec![vec![1, 3], vec![0, 2], vec![1, 3], vec![2, 0], vec![]];
+        let length = |a: usize, b: usize| match (a.min(b), a.max(b)) {
+            (0, 3) => 10.0,
+            _ => 1.0,
+        };
+
+        (adjacency, length)
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let (adjacency, length) = graph();
+
+        assert_eq!(shortest_path(&adjacency, 0, 4, length, |_| 0.0), None);
+    }
+
+    #[test]
+    fn start_is_goal() {
+        let (adjacency, length) = graph();
+
+        assert_eq!(
+            shortest_path(&adjacency, 2, 2, length, |_| 0.0),
+            Some((vec![2], 0.0))
+        );
+    }
+
+    #[test]
+    fn cost_picks_the_route() {
+        let (adjacency, length) = graph();
+
+        assert_eq!(
+            shortest_path(&adjacency, 0, 3, length, |_| 0.0),
+            Some((vec![0, 1, 2, 3], 3.0))
+        );
+        assert_eq!(
+            shortest_path(&adjacency, 0, 3, |_, _| 1.0, |_| 0.0),
+            Some((vec![0, 3], 1.0))
+        );
+    }
+
+    #[test]
+    fn heuristic_keeps_the_shortest_route() {
+        let (adjacency, length) = graph();
+        // -- Hops left along the chain, never more than the remaining length
+        let remaining = |node: usize| (3 - node.min(3)) as f32;
+
+        assert_eq!(
+            shortest_path(&adjacency, 0, 3, length, remaining),
+            Some((vec![0, 1, 2, 3], 3.0))
+        );
+    }
+}
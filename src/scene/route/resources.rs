@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RouteCost {
+    /// Shortest total length
+    Distance,
+    /// Fewest jumps
+    Hops,
+    /// Shortest total length, avoiding dust clouds and H-II regions
+    Hazard,
+}
+
+impl RouteCost {
+    pub fn next(self) -> Self {
+        match self {
+            RouteCost::Distance => RouteCost::Hops,
+            RouteCost::Hops => RouteCost::Hazard,
+            RouteCost::Hazard => RouteCost::Distance,
+        }
+    }
+}
+
+#[derive(Resource, Clone)]
+pub struct RouteSettings {
+    pub cost: RouteCost,
+    /// Extra cost of a lane crossing a hazard, in scene units
+    pub hazard_penalty: f32,
+    /// How close a lane has to pass to a hazard to cross it, in scene units
+    pub hazard_radius: f32,
+}
+
+impl Default for RouteSettings {
+    fn default() -> Self {
+        Self {
+            cost: RouteCost::Distance,
+            hazard_penalty: 5.0,
+            hazard_radius: 0.1,
+        }
+    }
+}
+
+/// Stars picked as the start and the destination of the route
+#[derive(Resource, Default)]
+pub struct RouteEnds {
+    pub start: Option<Entity>,
+    pub end: Option<Entity>,
+}
+
+/// The planned route, empty until both ends are picked and connected
+#[derive(Resource, Default)]
+pub struct Route {
+    pub waypoints: Vec<Entity>,
+    pub length: f32,
+    pub cost: f32,
+}
@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::configuration::resources::MapUnits;
+use crate::scene::catalog::CatalogEntry;
+use crate::scene::hyperlane::graph::SpatialGrid;
+use crate::scene::hyperlane::resources::*;
+use crate::scene::picking::{ActiveTool, BodyClicked};
+use crate::scene::route::components::*;
+use crate::scene::route::path::shortest_path;
+use crate::scene::route::resources::*;
+use crate::scene::*;
+use crate::ui::styles::*;
+
+const ROUTE_TOOL_KEY: KeyCode = KeyCode::KeyR;
+const ROUTE_COST_KEY: KeyCode = KeyCode::KeyC;
+const ROUTE_COLOR: Color = Color::srgb(1.0, 0.4, 0.1);
+/// Waypoints beyond this are summarized in the panel
+const MAX_LISTED_WAYPOINTS: usize = 15;
+
+pub fn toggle_route_tool(
+    kbd: Res<ButtonInput<KeyCode>>,
+    tool: Res<State<ActiveTool>>,
+    mut next_tool: ResMut<NextState<ActiveTool>>,
+    mut hyperlane_settings: ResMut<HyperlaneSettings>,
+) {
+    if !kbd.just_pressed(ROUTE_TOOL_KEY) {
+        return;
+    }
+
+    match tool.get() {
+        ActiveTool::Route => next_tool.set(ActiveTool::None),
+        _ => {
+            // -- Routes run along hyperlanes, make sure there are some
+            if !hyperlane_settings.enabled {
+                hyperlane_settings.enabled = true;
+            }
+            next_tool.set(ActiveTool::Route);
+        }
+    }
+}
+
+pub fn switch_route_cost(kbd: Res<ButtonInput<KeyCode>>, mut settings: ResMut<RouteSettings>) {
+    if kbd.just_pressed(ROUTE_COST_KEY) {
+        settings.cost = settings.cost.next();
+    }
+}
+
+pub fn pick_route_ends(
+    mut evr_clicked: EventReader<BodyClicked>,
+    mut ends: ResMut<RouteEnds>,
+    hyperlanes: Res<Hyperlanes>,
+) {
+    for ev in evr_clicked.read() {
+        // -- Only stars are connected by hyperlanes
+        if ev.kind != BodyKind::Star || !hyperlanes.nodes.contains(&ev.entity) {
+            continue;
+        }
+
+        // -- A click after a full route starts a new one
+        if ends.start.is_none() || ends.end.is_some() {
+            ends.start = Some(ev.entity);
+            ends.end = None;
+        } else {
+            ends.end = Some(ev.entity);
+        }
+    }
+}
+
+pub fn route_outdated(
+    ends: Res<RouteEnds>,
+    settings: Res<RouteSettings>,
+    hyperlanes: Res<Hyperlanes>,
+) -> bool {
+    ends.is_changed() || settings.is_changed() || hyperlanes.is_changed()
+}
+
+pub fn plan_route(
+    ends: Res<RouteEnds>,
+    settings: Res<RouteSettings>,
+    hyperlanes: Res<Hyperlanes>,
+    mut route: ResMut<Route>,
+    q_bodies: Query<(&GlobalTransform, &BodyKind)>,
+) {
+    *route = Route::default();
+
+    let index: HashMap<Entity, usize> = hyperlanes
+        .nodes
+        .iter()
+        .enumerate()
+        .map(|(index, entity)| (*entity, index))
+        .collect();
+
+    let (Some(start), Some(end)) = (
+        ends.start.and_then(|entity| index.get(&entity)),
+        ends.end.and_then(|entity| index.get(&entity)),
+    ) else {
+        return;
+    };
+
+    let positions: Vec<Vec2> = hyperlanes
+        .nodes
+        .iter()
+        .map(|entity| {
+            q_bodies
+                .get(*entity)
+                .map(|(transform, _)| transform.translation().xz())
+                .unwrap_or_default()
+        })
+        .collect();
+    let length = |a: usize, b: usize| positions[a].distance(positions[b]);
+    let remaining = |node: usize| positions[node].distance(positions[*end]);
+
+    let found = match settings.cost {
+        RouteCost::Distance => {
+            shortest_path(&hyperlanes.adjacency(), *start, *end, length, remaining)
+        }
+        RouteCost::Hops => {
+            shortest_path(&hyperlanes.adjacency(), *start, *end, |_, _| 1.0, |_| 0.0)
+        }
+        RouteCost::Hazard => {
+            let hazards: Vec<Vec2> = q_bodies
+                .iter()
+                .filter(|(_, kind)| matches!(kind, BodyKind::Dust | BodyKind::HII))
+                .map(|(transform, _)| transform.translation().xz())
+                .collect();
+            let grid = SpatialGrid::new(&hazards, settings.hazard_radius.max(0.5));
+
+            let cost = |a: usize, b: usize| {
+                let (start, end) = (positions[a], positions[b]);
+                let reach = start.distance(end) / 2.0 + settings.hazard_radius;
+                let crossings = grid
+                    .within(&hazards, start.lerp(end, 0.5), reach)
+                    .filter(|hazard| {
+                        segment_distance(hazards[*hazard], start, end) < settings.hazard_radius
+                    })
+                    .count();
+
+                length(a, b) + crossings as f32 * settings.hazard_penalty
+            };
+
+            shortest_path(&hyperlanes.adjacency(), *start, *end, cost, remaining)
+        }
+    };
+
+    if let Some((path, cost)) = found {
+        route.length = path.windows(2).map(|step| length(step[0], step[1])).sum();
+        route.cost = cost;
+        route.waypoints = path
+            .into_iter()
+            .map(|node| hyperlanes.nodes[node])
+            .collect();
+    }
+}
+
+pub fn draw_route(
+    ends: Res<RouteEnds>,
+    route: Res<Route>,
+    q_bodies: Query<&GlobalTransform>,
+    mut gizmos: Gizmos,
+) {
+    for entity in [ends.start, ends.end].into_iter().flatten() {
+        if let Ok(transform) = q_bodies.get(entity) {
+            gizmos.sphere(transform.translation(), Quat::IDENTITY, 0.1, ROUTE_COLOR);
+        }
+    }
+
+    // -- The route follows the stars as they orbit
+    let path = q_bodies
+        .iter_many(&route.waypoints)
+        .map(GlobalTransform::translation);
+
+    gizmos.linestrip(path, ROUTE_COLOR);
+}
+
+pub fn spawn_route_panel(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let wrapper = NodeBundle {
+        style: TOOL_PANEL_STYLE,
+        background_color: UI_BACKGROUND_COLOR,
+        border_radius: UI_BORDER_RADIUS,
+        ..default()
+    };
+    let text = TextBundle {
+        text: Text::from_section("", get_text_style(&asset_server, 16.0)),
+        ..default()
+    };
+
+    commands
+        .spawn((wrapper, RoutePanel))
+        .with_children(|parent| {
+            parent.spawn((text, RouteText));
+        });
+}
+
+pub fn despawn_route_panel(mut commands: Commands, panel_query: Query<Entity, With<RoutePanel>>) {
+    for entity in panel_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn update_route_panel(
+    ends: Res<RouteEnds>,
+    route: Res<Route>,
+    settings: Res<RouteSettings>,
+    units: Res<MapUnits>,
+    q_catalog: Query<&CatalogEntry>,
+    mut text_query: Query<&mut Text, With<RouteText>>,
+) {
+    let mut value = format!("Cost: {:?}\n", settings.cost);
+
+    if ends.start.is_none() {
+        value.push_str("Click a start star");
+    } else if ends.end.is_none() {
+        value.push_str("Click a destination star");
+    } else if route.waypoints.is_empty() {
+        value.push_str("No route between these stars");
+    } else {
+        value.push_str(&format!(
+            "Jumps: {}\nLength: {}\n",
+            route.waypoints.len() - 1,
+            units.format(route.length),
+        ));
+
+        for (step, entry) in q_catalog
+            .iter_many(&route.waypoints)
+            .take(MAX_LISTED_WAYPOINTS)
+            .enumerate()
+        {
+            value.push_str(&format!("\n{}. {}", step + 1, entry.label()));
+        }
+        if route.waypoints.len() > MAX_LISTED_WAYPOINTS {
+            value.push_str(&format!(
+                "\n... and {} more",
+                route.waypoints.len() - MAX_LISTED_WAYPOINTS
+            ));
+        }
+    }
+
+    for mut text in &mut text_query {
+        text.sections[0].value = value.clone();
+    }
+}
+
+/// Distance from a point to the segment between `start` and `end`
+fn segment_distance(point: Vec2, start: Vec2, end: Vec2) -> f32 {
+    let segment = end - start;
+    let along = (point - start).dot(segment) / segment.length_squared().max(f32::EPSILON);
+
+    point.distance(start + segment * along.clamp(0.0, 1.0))
+}
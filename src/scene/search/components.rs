@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct SearchInput;
+
+#[derive(Component)]
+pub struct SearchResultList;
+
+/// Entry of the result list, taking the camera to its body when clicked
+#[derive(Component)]
+pub struct SearchResultButton {
+    pub entity: Entity,
+}
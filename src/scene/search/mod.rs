@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+use crate::scene::search::resources::SearchResults;
+use crate::scene::search::systems::*;
+
+pub mod components;
+pub mod query;
+pub mod resources;
+pub mod systems;
+
+pub struct SearchPlugin;
+
+impl Plugin for SearchPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SearchResults>()
+            .add_systems(Startup, spawn_search_panel)
+            .add_systems(
+                Update,
+                (
+                    run_search.run_if(search_outdated),
+                    list_search_results.run_if(resource_changed::<SearchResults>),
+                    go_to_search_result,
+                )
+                    .chain(),
+            );
+    }
+}
@@ -0,0 +1,310 @@
+use crate::configuration::resources::{MapUnits, LIGHT_YEARS_PER_PARSEC};
+use crate::scene::catalog::CatalogEntry;
+use crate::scene::BodyKind;
+
+/// Body property a condition compares against
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Field {
+    /// Galactocentric radius, in scene units
+    Radius,
+    /// Galactic angle, in degrees
+    Angle,
+    Brightness,
+    Id,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Comparison {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Equal,
+}
+
+#[derive(Debug, PartialEq)]
+struct Condition {
+    field: Field,
+    comparison: Comparison,
+    value: f32,
+}
+
+/// What is known about a body when matching it
+pub struct Candidate<'a> {
+    pub kind: BodyKind,
+    pub entry: &'a CatalogEntry,
+    pub radius: f32,
+    pub angle: f32,
+    pub brightness: f32,
+}
+
+/// A parsed search, e.g. `kind:hii radius<5 sector:c-7 kor`.
+/// Every term has to match; bare words match the name, bare numbers the id.
+#[derive(Debug, Default, PartialEq)]
+pub struct SearchQuery {
+    kind: Option<BodyKind>,
+    sector: Option<String>,
+    conditions: Vec<Condition>,
+    words: Vec<String>,
+}
+
+impl SearchQuery {
+    /// Parses a query; distances without a unit are in the displayed unit
+    pub fn parse(text: &str, units: &MapUnits) -> Result<Self, String> {
+        let mut query = SearchQuery::default();
+
+        for term in text.split_whitespace() {
+            let term = term.to_lowercase();
+
+            if let Some(kind) = term.strip_prefix("kind:") {
+                query.kind = Some(parse_kind(kind)?);
+            } else if let Some(sector) = term.strip_prefix("sector:") {
+                query.sector = Some(sector.to_string());
+            } else if let Some(id) = term.strip_prefix('#').or(term.strip_prefix("id:")) {
+                query.conditions.push(Condition {
+                    field: Field::Id,
+                    comparison: Comparison::Equal,
+                    value: parse_id(id)?,
+                });
+            } else if let Some(condition) = parse_condition(&term, units)? {
+                query.conditions.push(condition);
+            } else if term.chars().all(|c| c.is_ascii_digit()) {
+                query.conditions.push(Condition {
+                    field: Field::Id,
+                    comparison: Comparison::Equal,
+                    value: parse_id(&term)?,
+                });
+            } else {
+                query.words.push(term);
+            }
+        }
+
+        Ok(query)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == SearchQuery::default()
+    }
+
+    /// Whether the query filters by sector, which is costly to work out per body
+    pub fn needs_sector(&self) -> bool {
+        self.sector.is_some()
+    }
+
+    pub fn matches(&self, candidate: &Candidate, sector: Option<&str>) -> bool {
+        if self.kind.is_some_and(|kind| kind != candidate.kind) {
+            return false;
+        }
+        if let Some(wanted) = &self.sector {
+            if !sector.is_some_and(|sector| sector.eq_ignore_ascii_case(wanted)) {
+                return false;
+            }
+        }
+
+        let name = candidate.entry.name.to_lowercase();
+        if !self.words.iter().all(|word| name.contains(word.as_str())) {
+            return false;
+        }
+
+        self.conditions.iter().all(|condition| {
+            let value = match condition.field {
+                Field::Radius => candidate.radius,
+                Field::Angle => candidate.angle,
+                Field::Brightness => candidate.brightness,
+                Field::Id => candidate.entry.id as f32,
+            };
+
+            match condition.comparison {
+                Comparison::Less => value < condition.value,
+                Comparison::LessOrEqual => value <= condition.value,
+                Comparison::Greater => value > condition.value,
+                Comparison::GreaterOrEqual => value >= condition.value,
+                Comparison::Equal => value == condition.value,
+            }
+        })
+    }
+}
+
+fn parse_kind(kind: &str) -> Result<BodyKind, String> {
+    match kind {
+        "star" | "stars" => Ok(BodyKind::Star),
+        "dust" => Ok(BodyKind::Dust),
+        "hii" | "h-ii" | "h2" => Ok(BodyKind::HII),
+        "filament" | "filaments" => Ok(BodyKind::Filament),
+        _ => Err(format!("Unknown kind \"{}\"", kind)),
+    }
+}
+
+fn parse_id(id: &str) -> Result<f32, String> {
+    id.parse::<u32>()
+        .map(|id| id as f32)
+        .map_err(|_| format!("Invalid id \"{}\"", id))
+}
+
+/// Parses terms like `radius<5`, `angle>=90` or `brightness=1`
+fn parse_condition(term: &str, units: &MapUnits) -> Result<Option<Condition>, String> {
+    let Some(position) = term.find(['<', '>', '=']) else {
+        return Ok(None);
+    };
+    let (name, rest) = term.split_at(position);
+
+    let field = match name {
+        "radius" | "r" => Field::Radius,
+        "angle" => Field::Angle,
+        "brightness" => Field::Brightness,
+        "id" => Field::Id,
+        _ => return Err(format!("Unknown property \"{}\"", name)),
+    };
+
+    let (comparison, value) = [
+        ("<=", Comparison::LessOrEqual),
+        (">=", Comparison::GreaterOrEqual),
+        ("<", Comparison::Less),
+        (">", Comparison::Greater),
+        ("=", Comparison::Equal),
+    ]
+    .into_iter()
+    .find_map(|(symbol, comparison)| rest.strip_prefix(symbol).map(|value| (comparison, value)))
+    .ok_or_else(|| format!("Invalid comparison in \"{}\"", term))?;
+
+    let value = match field {
+        Field::Radius => parse_distance(value, units)?,
+        _ => value
+            .parse::<f32>()
+            .map_err(|_| format!("Invalid number \"{}\"", value))?,
+    };
+
+    Ok(Some(Condition {
+        field,
+        comparison,
+        value,
+    }))
+}
+
+/// Parses a distance like `5`, `3kpc` or `2000ly` into scene units
+fn parse_distance(text: &str, units: &MapUnits) -> Result<f32, String> {
+    let split = text
+        .find(|c: char| c.is_ascii_alphabetic())
+        .unwrap_or(text.len());
+    let (number, suffix) = text.split_at(split);
+
+    let number = number
+        .parse::<f32>()
+        .map_err(|_| format!("Invalid distance \"{}\"", text))?;
+
+    let scene_per_parsec = 1.0 / units.parsecs_per_scene_unit;
+    let scale = match suffix {
+        "" => units.convert(1.0).recip(),
+        "u" => 1.0,
+        "pc" => scene_per_parsec,
+        "kpc" => scene_per_parsec * 1_000.0,
+        "ly" => scene_per_parsec / LIGHT_YEARS_PER_PARSEC,
+        _ => return Err(format!("Unknown unit \"{}\"", suffix)),
+    };
+
+    Ok(number * scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configuration::resources::DistanceUnit;
+
+    /// A kiloparsec per scene unit keeps the expected distances round
+    const UNITS: MapUnits = MapUnits {
+        unit: DistanceUnit::Parsec,
+        parsecs_per_scene_unit: 1000.0,
+    };
+
+    fn parse(text: &str) -> Result<SearchQuery, String> {
+        SearchQuery::parse(text, &UNITS)
+    }
+
+    fn condition(field: Field, comparison: Comparison, value: f32) -> Condition {
+        Condition {
+            field,
+            comparison,
+            value,
+        }
+    }
+
+    /// Value of the only condition of a query
+    fn single_value(text: &str) -> f32 {
+        let query = parse(text).unwrap();
+        assert_eq!(query.conditions.len(), 1);
+
+        query.conditions[0].value
+    }
+
+    #[test]
+    fn kinds() {
+        assert_eq!(parse("kind:hii").unwrap().kind, Some(BodyKind::HII));
+        assert_eq!(parse("KIND:Stars").unwrap().kind, Some(BodyKind::Star));
+        assert_eq!(parse("kind:filament").unwrap().kind, Some(BodyKind::Filament));
+        assert!(parse("kind:comet").is_err());
+    }
+
+    #[test]
+    fn ids() {
+        let id = vec![condition(Field::Id, Comparison::Equal, 42.0)];
+
+        assert_eq!(parse("#42").unwrap().conditions, id);
+        assert_eq!(parse("id:42").unwrap().conditions, id);
+        assert_eq!(parse("42").unwrap().conditions, id);
+        assert!(parse("#x").is_err());
+        assert!(parse("#-1").is_err());
+    }
+
+    #[test]
+    fn comparisons() {
+        assert_eq!(
+            parse("angle>=90 brightness<0.5 id=7").unwrap().conditions,
+            vec![
+                condition(Field::Angle, Comparison::GreaterOrEqual, 90.0),
+                condition(Field::Brightness, Comparison::Less, 0.5),
+                condition(Field::Id, Comparison::Equal, 7.0),
+            ]
+        );
+        assert_eq!(
+            parse("r<=2u angle>1").unwrap().conditions,
+            vec![
+                condition(Field::Radius, Comparison::LessOrEqual, 2.0),
+                condition(Field::Angle, Comparison::Greater, 1.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn distance_units() {
+        // -- Without a suffix distances are in the displayed unit, parsecs here
+        assert_eq!(single_value("radius<500"), 0.5);
+        assert_eq!(single_value("radius<2u"), 2.0);
+        assert_eq!(single_value("radius<500pc"), 0.5);
+        assert_eq!(single_value("radius<3kpc"), 3.0);
+        assert!((single_value("radius<3261.6ly") - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn words_and_sectors() {
+        let query = parse("Kor sector:C-7").unwrap();
+
+        assert_eq!(query.words, vec!["kor".to_string()]);
+        assert_eq!(query.sector.as_deref(), Some("c-7"));
+        assert!(query.needs_sector());
+        assert!(parse("   ").unwrap().is_empty());
+    }
+
+    #[test]
+    fn malformed_terms() {
+        for text in [
+            "speed<3",
+            "radius<<3",
+            "radius<3parsecs",
+            "radius<",
+            "angle>abc",
+            "id>",
+        ] {
+            assert!(parse(text).is_err(), "{} should be rejected", text);
+        }
+    }
+}
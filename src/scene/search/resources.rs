@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+
+#[derive(Resource, Default)]
+pub struct SearchResults {
+    /// First few matching bodies, with their labels
+    pub matches: Vec<(Entity, String)>,
+    /// Number of bodies matching, listed or not
+    pub total: usize,
+    /// Why the query couldn't be understood
+    pub error: Option<String>,
+}
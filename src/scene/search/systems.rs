@@ -0,0 +1,212 @@
+use bevy::prelude::*;
+
+use crate::configuration::resources::{Configuration, MapUnits};
+use crate::scene::camera::events::FocusCamera;
+use crate::scene::catalog::CatalogEntry;
+use crate::scene::picking::ActiveTool;
+use crate::scene::search::components::*;
+use crate::scene::search::query::*;
+use crate::scene::search::resources::SearchResults;
+use crate::scene::sector::resources::*;
+use crate::scene::selection::resources::SelectedBody;
+use crate::scene::*;
+use crate::ui::components::TextInput;
+use crate::ui::events::TextSubmitted;
+use crate::ui::styles::*;
+use crate::ui::systems::spawn_text_input;
+
+const MAX_LISTED_RESULTS: usize = 10;
+/// Distance the camera stops at from a found body
+const RESULT_VIEW_RADIUS: f32 = 1.5;
+
+pub fn spawn_search_panel(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let wrapper = NodeBundle {
+        style: SEARCH_PANEL_STYLE,
+        background_color: UI_BACKGROUND_COLOR,
+        border_radius: UI_BORDER_RADIUS,
+        ..default()
+    };
+    let list = NodeBundle {
+        style: SEARCH_RESULT_LIST_STYLE,
+        ..default()
+    };
+
+    // -- Interaction lets clicks on the panel be told apart from clicks on the map
+    commands
+        .spawn((wrapper, Interaction::default()))
+        .with_children(|parent| {
+            spawn_text_input(
+                parent,
+                &asset_server,
+                "",
+                "Search: name, #id, kind:hii radius<5",
+                TEXT_INPUT_STYLE,
+                SearchInput,
+            );
+            parent.spawn((list, SearchResultList));
+        });
+}
+
+/// Searches again when the query is edited or the galaxy regenerated
+pub fn search_outdated(
+    input_query: Query<(), (Changed<TextInput>, With<SearchInput>)>,
+    added_query: Query<(), Added<OrbitingBody>>,
+    units: Res<MapUnits>,
+    sectors: Res<SectorSettings>,
+) -> bool {
+    !input_query.is_empty() || !added_query.is_empty() || units.is_changed() || sectors.is_changed()
+}
+
+pub fn run_search(
+    input_query: Query<&TextInput, With<SearchInput>>,
+    units: Res<MapUnits>,
+    sectors: Res<SectorSettings>,
+    configuration: Res<Configuration>,
+    mut results: ResMut<SearchResults>,
+    q_bodies: Query<(Entity, &OrbitingBody, &BodyKind, &CatalogEntry)>,
+) {
+    let Ok(input) = input_query.get_single() else {
+        return;
+    };
+
+    *results = SearchResults::default();
+
+    let query = match SearchQuery::parse(&input.value, &units) {
+        Ok(query) if query.is_empty() => return,
+        Ok(query) => query,
+        Err(error) => {
+            results.error = Some(error);
+            return;
+        }
+    };
+
+    let extent = configuration.galaxy_radius();
+    let mut matches: Vec<(Entity, &CatalogEntry)> = Vec::new();
+    for (entity, body, kind, entry) in &q_bodies {
+        let position = body.position();
+        let candidate = Candidate {
+            kind: *kind,
+            entry,
+            radius: galactocentric_radius(position),
+            angle: galactic_angle(position).to_degrees().rem_euclid(360.0),
+            brightness: body.dimming_channel,
+        };
+        let sector = query
+            .needs_sector()
+            .then(|| sectors.sector_at(position, extent))
+            .flatten()
+            .map(|sector| sector.name);
+
+        if query.matches(&candidate, sector.as_deref()) {
+            matches.push((entity, entry));
+        }
+    }
+
+    // -- Lowest ids first, so the same search lists the same bodies
+    matches.sort_by_key(|(_, entry)| entry.id);
+
+    results.total = matches.len();
+    results.matches = matches
+        .into_iter()
+        .take(MAX_LISTED_RESULTS)
+        .map(|(entity, entry)| (entity, entry.label()))
+        .collect();
+}
+
+pub fn list_search_results(
+    mut commands: Commands,
+    results: Res<SearchResults>,
+    asset_server: Res<AssetServer>,
+    list_query: Query<Entity, With<SearchResultList>>,
+) {
+    let Ok(list) = list_query.get_single() else {
+        return;
+    };
+
+    commands.entity(list).despawn_descendants();
+    commands.entity(list).with_children(|parent| {
+        let style = get_text_style(&asset_server, 14.0);
+
+        if let Some(error) = &results.error {
+            parent.spawn(TextBundle::from_section(error.clone(), style.clone()));
+        }
+
+        for (entity, label) in &results.matches {
+            let button = NodeBundle {
+                style: SEARCH_RESULT_STYLE,
+                border_radius: UI_BORDER_RADIUS,
+                background_color: ELEMENT_BACKGROUND_COLOR,
+                ..default()
+            };
+
+            parent
+                .spawn((
+                    button,
+                    SearchResultButton { entity: *entity },
+                    Interaction::default(),
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle::from_section(label.clone(), style.clone()));
+                });
+        }
+
+        if results.total > results.matches.len() {
+            let more = format!("... and {} more", results.total - results.matches.len());
+            parent.spawn(TextBundle::from_section(more, style.clone()));
+        }
+    });
+}
+
+/// Clicking a result, or pressing Enter for the first one,
+/// flies the camera to the body and selects it
+#[allow(clippy::too_many_arguments)]
+pub fn go_to_search_result(
+    mut button_query: Query<
+        (&SearchResultButton, &Interaction, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    input_query: Query<(), With<SearchInput>>,
+    results: Res<SearchResults>,
+    q_bodies: Query<&GlobalTransform, With<OrbitingBody>>,
+    mut evr_submitted: EventReader<TextSubmitted>,
+    mut evw_focus: EventWriter<FocusCamera>,
+    mut selected: ResMut<SelectedBody>,
+    mut next_tool: ResMut<NextState<ActiveTool>>,
+) {
+    let mut target = None;
+
+    for (button, interaction, mut background_color) in &mut button_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *background_color = ELEMENT_BACKGROUND_COLOR_PRESSED;
+                target = Some(button.entity);
+            }
+            Interaction::Hovered => {
+                *background_color = ELEMENT_BACKGROUND_COLOR_HOVERED;
+            }
+            Interaction::None => {
+                *background_color = ELEMENT_BACKGROUND_COLOR;
+            }
+        }
+    }
+
+    for ev in evr_submitted.read() {
+        if input_query.contains(ev.entity) {
+            target = results.matches.first().map(|(entity, _)| *entity);
+        }
+    }
+
+    let Some(entity) = target else {
+        return;
+    };
+    let Ok(transform) = q_bodies.get(entity) else {
+        return;
+    };
+
+    evw_focus.send(FocusCamera {
+        center: transform.translation(),
+        radius: RESULT_VIEW_RADIUS,
+    });
+    next_tool.set(ActiveTool::None);
+    selected.entity = Some(entity);
+}
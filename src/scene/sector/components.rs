@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+/// Clickable sector name pinned to the sector's center
+#[derive(Component)]
+pub struct SectorLabel {
+    pub center: Vec3,
+    pub size: f32,
+}
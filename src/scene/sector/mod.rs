@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+
+use crate::configuration::resources::Configuration;
+use crate::scene::orbiting_body::events::BodiesReshaped;
+use crate::scene::sector::resources::SectorSettings;
+use crate::scene::sector::systems::*;
+
+pub mod components;
+pub mod resources;
+pub mod systems;
+
+pub struct SectorPlugin;
+
+impl Plugin for SectorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SectorSettings>().add_systems(
+            Update,
+            (
+                control_sectors,
+                spawn_sector_labels.run_if(
+                    // -- Reading the events first, or a skipped one would still be pending
+                    on_event::<BodiesReshaped>()
+                        .or_else(resource_changed::<SectorSettings>)
+                        .or_else(resource_changed::<Configuration>),
+                ),
+                (draw_sectors, place_sector_labels, go_to_sector).run_if(sectors_enabled),
+            )
+                .chain(),
+        );
+    }
+}
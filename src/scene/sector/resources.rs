@@ -0,0 +1,127 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SectorScheme {
+    /// Radial bands around the center, split into angular wedges
+    Polar,
+    /// Square cells over the galactic plane
+    Grid,
+}
+
+/// A named part of the galactic plane
+pub struct Sector {
+    pub name: String,
+    /// Middle of the sector, as (x, z)
+    pub center: Vec2,
+    /// Rough width of the sector, in scene units
+    pub size: f32,
+}
+
+#[derive(Resource, Clone)]
+pub struct SectorSettings {
+    /// Whether the sectors are drawn and labeled on the map
+    pub enabled: bool,
+    pub scheme: SectorScheme,
+    /// Radial bands of the polar scheme, lettered from the center out
+    pub bands: u32,
+    /// Angular wedges of the polar scheme, numbered counter-clockwise from +X
+    pub wedges: u32,
+    /// Cells per side of the grid scheme, lettered along X and numbered along Z
+    pub cells: u32,
+}
+
+impl Default for SectorSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            scheme: SectorScheme::Polar,
+            bands: 5,
+            wedges: 12,
+            cells: 8,
+        }
+    }
+}
+
+impl SectorSettings {
+    /// Sector above or below which a point lies, given the galaxy radius
+    pub fn sector_at(&self, position: Vec3, extent: f32) -> Option<Sector> {
+        match self.scheme {
+            SectorScheme::Polar => {
+                let radius = galactocentric_radius(position);
+                let band = (radius / extent * self.bands as f32).floor() as u32;
+                let angle = galactic_angle(position).rem_euclid(TAU);
+                let wedge = (angle / TAU * self.wedges as f32).floor() as u32;
+
+                (band < self.bands)
+                    .then(|| self.polar_sector(band, wedge.min(self.wedges - 1), extent))
+            }
+            SectorScheme::Grid => {
+                let cell =
+                    (position.xz() + Vec2::splat(extent)) / (2.0 * extent) * self.cells as f32;
+                let (column, row) = (cell.x.floor(), cell.y.floor());
+                let range = 0.0..self.cells as f32;
+
+                (range.contains(&column) && range.contains(&row))
+                    .then(|| self.grid_sector(column as u32, row as u32, extent))
+            }
+        }
+    }
+
+    /// Every sector of the current scheme
+    pub fn sectors(&self, extent: f32) -> Vec<Sector> {
+        match self.scheme {
+            SectorScheme::Polar => (0..self.bands)
+                .flat_map(|band| (0..self.wedges).map(move |wedge| (band, wedge)))
+                .map(|(band, wedge)| self.polar_sector(band, wedge, extent))
+                .collect(),
+            SectorScheme::Grid => (0..self.cells)
+                .flat_map(|column| (0..self.cells).map(move |row| (column, row)))
+                .map(|(column, row)| self.grid_sector(column, row, extent))
+                .collect(),
+        }
+    }
+
+    fn polar_sector(&self, band: u32, wedge: u32, extent: f32) -> Sector {
+        let band_width = extent / self.bands as f32;
+        let radius = (band as f32 + 0.5) * band_width;
+        let angle = (wedge as f32 + 0.5) * TAU / self.wedges as f32;
+
+        Sector {
+            name: sector_name(band, wedge),
+            center: Vec2::new(angle.cos(), -angle.sin()) * radius,
+            size: band_width,
+        }
+    }
+
+    fn grid_sector(&self, column: u32, row: u32, extent: f32) -> Sector {
+        let cell_size = 2.0 * extent / self.cells as f32;
+
+        Sector {
+            name: sector_name(column, row),
+            center: Vec2::new(
+                -extent + (column as f32 + 0.5) * cell_size,
+                -extent + (row as f32 + 0.5) * cell_size,
+            ),
+            size: cell_size,
+        }
+    }
+}
+
+/// Distance from the galactic center within the galactic plane
+pub fn galactocentric_radius(position: Vec3) -> f32 {
+    position.xz().length()
+}
+
+/// Angle around the galactic center, counter-clockwise from +X seen from above
+pub fn galactic_angle(position: Vec3) -> f32 {
+    (-position.z).atan2(position.x)
+}
+
+/// Names like "C-7": a letter for the first coordinate, a number for the second
+fn sector_name(letter: u32, number: u32) -> String {
+    let letter = char::from_u32('A' as u32 + letter % 26).unwrap_or('?');
+
+    format!("{}-{}", letter, number + 1)
+}
@@ -0,0 +1,146 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::configuration::resources::Configuration;
+use crate::scene::camera::components::PanOrbitState;
+use crate::scene::camera::events::FocusCamera;
+use crate::scene::sector::components::*;
+use crate::scene::sector::resources::*;
+use crate::ui::styles::*;
+
+const SECTOR_TOGGLE_KEY: KeyCode = KeyCode::KeyZ;
+const SECTOR_SCHEME_KEY: KeyCode = KeyCode::KeyY;
+const SECTOR_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.15);
+const SECTOR_LABEL_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.6);
+/// How many sectors across the camera frames after going to one
+const SECTOR_VIEW_SIZE: f32 = 3.0;
+
+pub fn sectors_enabled(settings: Res<SectorSettings>) -> bool {
+    settings.enabled
+}
+
+pub fn control_sectors(kbd: Res<ButtonInput<KeyCode>>, mut settings: ResMut<SectorSettings>) {
+    // -- Ctrl+Z is undo
+    let ctrl = kbd.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+
+    if kbd.just_pressed(SECTOR_TOGGLE_KEY) && !ctrl {
+        settings.enabled = !settings.enabled;
+    }
+    if kbd.just_pressed(SECTOR_SCHEME_KEY) && settings.enabled {
+        settings.scheme = match settings.scheme {
+            SectorScheme::Polar => SectorScheme::Grid,
+            SectorScheme::Grid => SectorScheme::Polar,
+        };
+    }
+}
+
+pub fn spawn_sector_labels(
+    mut commands: Commands,
+    settings: Res<SectorSettings>,
+    configuration: Res<Configuration>,
+    asset_server: Res<AssetServer>,
+    label_query: Query<Entity, With<SectorLabel>>,
+) {
+    for entity in label_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    if !settings.enabled {
+        return;
+    }
+
+    let mut style = get_text_style(&asset_server, 12.0);
+    style.color = SECTOR_LABEL_COLOR;
+
+    for sector in settings.sectors(configuration.galaxy_radius()) {
+        let label = TextBundle {
+            text: Text::from_section(sector.name, style.clone()),
+            style: GRID_LABEL_STYLE,
+            visibility: Visibility::Hidden,
+            ..default()
+        };
+
+        commands.spawn((
+            label,
+            SectorLabel {
+                center: Vec3::new(sector.center.x, 0.0, sector.center.y),
+                size: sector.size,
+            },
+            Interaction::default(),
+        ));
+    }
+}
+
+pub fn draw_sectors(
+    settings: Res<SectorSettings>,
+    configuration: Res<Configuration>,
+    mut gizmos: Gizmos,
+) {
+    let extent = configuration.galaxy_radius();
+
+    match settings.scheme {
+        SectorScheme::Polar => {
+            for band in 1..=settings.bands {
+                let radius = band as f32 * extent / settings.bands as f32;
+                gizmos.circle(Vec3::ZERO, Dir3::Y, radius, SECTOR_COLOR);
+            }
+            for wedge in 0..settings.wedges {
+                let angle = wedge as f32 * TAU / settings.wedges as f32;
+                let direction = Vec3::new(angle.cos(), 0.0, -angle.sin());
+                gizmos.line(Vec3::ZERO, direction * extent, SECTOR_COLOR);
+            }
+        }
+        SectorScheme::Grid => {
+            for line in 0..=settings.cells {
+                let offset = -extent + line as f32 * 2.0 * extent / settings.cells as f32;
+                gizmos.line(
+                    Vec3::new(offset, 0.0, -extent),
+                    Vec3::new(offset, 0.0, extent),
+                    SECTOR_COLOR,
+                );
+                gizmos.line(
+                    Vec3::new(-extent, 0.0, offset),
+                    Vec3::new(extent, 0.0, offset),
+                    SECTOR_COLOR,
+                );
+            }
+        }
+    }
+}
+
+pub fn place_sector_labels(
+    q_camera: Query<(&Camera, &GlobalTransform), With<PanOrbitState>>,
+    mut label_query: Query<(&SectorLabel, &mut Style, &mut Visibility)>,
+) {
+    let Ok((camera, camera_transform)) = q_camera.get_single() else {
+        return;
+    };
+
+    for (label, mut style, mut visibility) in &mut label_query {
+        match camera.world_to_viewport(camera_transform, label.center) {
+            Some(position) => {
+                style.left = Val::Px(position.x);
+                style.top = Val::Px(position.y);
+                *visibility = Visibility::Visible;
+            }
+            None => {
+                *visibility = Visibility::Hidden;
+            }
+        }
+    }
+}
+
+pub fn go_to_sector(
+    label_query: Query<(&SectorLabel, &Interaction), Changed<Interaction>>,
+    mut evw_focus: EventWriter<FocusCamera>,
+) {
+    for (label, interaction) in &label_query {
+        if *interaction == Interaction::Pressed {
+            evw_focus.send(FocusCamera {
+                center: label.center,
+                radius: label.size * SECTOR_VIEW_SIZE,
+            });
+        }
+    }
+}
@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct InfoPanel;
+
+#[derive(Component)]
+pub struct InfoText;
@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+use crate::scene::picking::ActiveTool;
+use crate::scene::selection::resources::SelectedBody;
+use crate::scene::selection::systems::*;
+
+pub mod components;
+pub mod resources;
+pub mod systems;
+
+pub struct SelectionPlugin;
+
+impl Plugin for SelectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SelectedBody>()
+            .add_systems(OnExit(ActiveTool::None), clear_selection)
+            .add_systems(
+                Update,
+                (
+                    select_body.run_if(in_state(ActiveTool::None)),
+                    spawn_info_panel.run_if(resource_changed::<SelectedBody>),
+                    (draw_selection, update_info_panel),
+                )
+                    .chain(),
+            );
+    }
+}
@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+/// Body whose details are shown in the info panel
+#[derive(Resource, Default)]
+pub struct SelectedBody {
+    pub entity: Option<Entity>,
+}
@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+
+use crate::configuration::resources::{Configuration, MapUnits};
+use crate::scene::catalog::CatalogEntry;
+use crate::scene::picking::BodyClicked;
+use crate::scene::sector::resources::*;
+use crate::scene::selection::components::*;
+use crate::scene::selection::resources::SelectedBody;
+use crate::scene::*;
+use crate::ui::styles::*;
+
+const DESELECT_KEY: KeyCode = KeyCode::Escape;
+const SELECTION_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
+
+pub fn select_body(
+    kbd: Res<ButtonInput<KeyCode>>,
+    mut evr_clicked: EventReader<BodyClicked>,
+    mut selected: ResMut<SelectedBody>,
+) {
+    if let Some(ev) = evr_clicked.read().last() {
+        selected.entity = Some(ev.entity);
+    }
+    if kbd.just_pressed(DESELECT_KEY) && selected.entity.is_some() {
+        selected.entity = None;
+    }
+}
+
+pub fn clear_selection(mut selected: ResMut<SelectedBody>) {
+    selected.entity = None;
+}
+
+pub fn spawn_info_panel(
+    mut commands: Commands,
+    selected: Res<SelectedBody>,
+    asset_server: Res<AssetServer>,
+    panel_query: Query<Entity, With<InfoPanel>>,
+) {
+    for entity in panel_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    if selected.entity.is_none() {
+        return;
+    }
+
+    let wrapper = NodeBundle {
+        style: TOOL_PANEL_STYLE,
+        background_color: UI_BACKGROUND_COLOR,
+        border_radius: UI_BORDER_RADIUS,
+        ..default()
+    };
+    let text = TextBundle {
+        text: Text::from_section("", get_text_style(&asset_server, 16.0)),
+        ..default()
+    };
+
+    commands
+        .spawn((wrapper, InfoPanel))
+        .with_children(|parent| {
+            parent.spawn((text, InfoText));
+        });
+}
+
+pub fn draw_selection(
+    mut selected: ResMut<SelectedBody>,
+    q_bodies: Query<&GlobalTransform, With<OrbitingBody>>,
+    mut gizmos: Gizmos,
+) {
+    let Some(entity) = selected.entity else {
+        return;
+    };
+
+    // -- The body is gone after the galaxy is regenerated
+    let Ok(transform) = q_bodies.get(entity) else {
+        selected.entity = None;
+        return;
+    };
+
+    gizmos.sphere(
+        transform.translation(),
+        Quat::IDENTITY,
+        0.1,
+        SELECTION_COLOR,
+    );
+}
+
+pub fn update_info_panel(
+    selected: Res<SelectedBody>,
+    sectors: Res<SectorSettings>,
+    configuration: Res<Configuration>,
+    units: Res<MapUnits>,
+    q_bodies: Query<(&GlobalTransform, &BodyKind, &CatalogEntry)>,
+    mut text_query: Query<&mut Text, With<InfoText>>,
+) {
+    let Some((transform, kind, entry)) = selected.entity.and_then(|entity| q_bodies.get(entity).ok())
+    else {
+        return;
+    };
+
+    let position = transform.translation();
+    let sector = sectors
+        .sector_at(position, configuration.galaxy_radius())
+        .map_or(String::from("Outside the map"), |sector| sector.name);
+
+    let value = format!(
+        "{}\nSector: {}\nPosition: ({:.2}, {:.2})\nRadius: {}\nAngle: {:.1}°",
+        entry.describe(*kind),
+        sector,
+        position.x,
+        position.z,
+        units.format(galactocentric_radius(position)),
+        galactic_angle(position).to_degrees().rem_euclid(360.0),
+    );
+
+    for mut text in &mut text_query {
+        text.sections[0].value = value.clone();
+    }
+}
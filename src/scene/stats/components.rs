@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+
+/// Title of the stats panel, collapsing or expanding it when clicked
+#[derive(Component)]
+pub struct StatsToggle;
+
+#[derive(Component)]
+pub struct StatsContent;
+
+#[derive(Component)]
+pub struct StatsText;
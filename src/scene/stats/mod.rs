@@ -0,0 +1,35 @@
+use bevy::diagnostic::{EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin};
+use bevy::prelude::*;
+
+use crate::scene::stats::resources::StatsSettings;
+use crate::scene::stats::systems::*;
+
+pub mod components;
+pub mod resources;
+pub mod systems;
+
+pub struct StatsPlugin;
+
+impl Plugin for StatsPlugin {
+    fn build(&self, app: &mut App) {
+        // -- The FPS overlay already measures frame times, the entity count is ours
+        if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
+            app.add_plugins(FrameTimeDiagnosticsPlugin);
+        }
+        if !app.is_plugin_added::<EntityCountDiagnosticsPlugin>() {
+            app.add_plugins(EntityCountDiagnosticsPlugin);
+        }
+
+        app.init_resource::<StatsSettings>()
+            .add_systems(Startup, spawn_stats_panel)
+            .add_systems(
+                Update,
+                (
+                    toggle_stats_panel,
+                    show_stats_panel.run_if(resource_changed::<StatsSettings>),
+                    update_stats,
+                )
+                    .chain(),
+            );
+    }
+}
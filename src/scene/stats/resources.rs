@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+
+#[derive(Resource)]
+pub struct StatsSettings {
+    pub expanded: bool,
+    /// How often the statistics are gathered again
+    pub refresh: Timer,
+}
+
+impl Default for StatsSettings {
+    fn default() -> Self {
+        Self {
+            expanded: true,
+            refresh: Timer::from_seconds(0.5, TimerMode::Repeating),
+        }
+    }
+}
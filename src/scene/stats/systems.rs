@@ -0,0 +1,201 @@
+use bevy::diagnostic::{
+    DiagnosticPath, DiagnosticsStore, EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin,
+};
+use bevy::prelude::*;
+
+use crate::configuration::resources::{Configuration, MapUnits};
+use crate::scene::stats::components::*;
+use crate::scene::stats::resources::StatsSettings;
+use crate::scene::*;
+use crate::ui::styles::*;
+
+pub fn spawn_stats_panel(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let wrapper = NodeBundle {
+        style: STATS_PANEL_STYLE,
+        background_color: UI_BACKGROUND_COLOR,
+        border_radius: UI_BORDER_RADIUS,
+        ..default()
+    };
+    let title = TextBundle {
+        text: Text::from_section("", get_text_style(&asset_server, 18.0)),
+        ..default()
+    };
+    let text = TextBundle {
+        text: Text::from_section("", get_text_style(&asset_server, 14.0)),
+        ..default()
+    };
+
+    // -- Interaction lets clicks on the panel be told apart from clicks on the map
+    commands
+        .spawn((wrapper, Interaction::default()))
+        .with_children(|parent| {
+            parent.spawn((title, StatsToggle, Interaction::default()));
+            parent.spawn((text, StatsText, StatsContent));
+        });
+}
+
+pub fn toggle_stats_panel(
+    toggle_query: Query<&Interaction, (Changed<Interaction>, With<StatsToggle>)>,
+    mut settings: ResMut<StatsSettings>,
+) {
+    for interaction in &toggle_query {
+        if *interaction == Interaction::Pressed {
+            settings.expanded = !settings.expanded;
+        }
+    }
+}
+
+pub fn show_stats_panel(
+    settings: Res<StatsSettings>,
+    mut title_query: Query<&mut Text, With<StatsToggle>>,
+    mut content_query: Query<&mut Style, With<StatsContent>>,
+) {
+    let marker = if settings.expanded { "-" } else { "+" };
+    for mut title in &mut title_query {
+        title.sections[0].value = format!("Statistics [{}]", marker);
+    }
+
+    for mut style in &mut content_query {
+        style.display = if settings.expanded {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+}
+
+/// Counts and orbit ranges of the bodies
+#[derive(Debug, PartialEq)]
+struct BodyStats {
+    counts: [usize; BodyKind::ALL.len()],
+    /// Shortest, longest and mean semi-major axis, none without bodies
+    radius: Option<(f32, f32, f32)>,
+    /// Slowest and fastest orbit speed, none without bodies
+    speed: Option<(f32, f32)>,
+}
+
+impl BodyStats {
+    fn gather<'a>(bodies: impl IntoIterator<Item = (&'a OrbitingBody, &'a BodyKind)>) -> Self {
+        let mut counts = [0usize; BodyKind::ALL.len()];
+        let mut radius = (f32::MAX, f32::MIN, 0.0);
+        let mut speed = (f32::MAX, f32::MIN);
+        let mut body_count = 0;
+
+        for (body, kind) in bodies {
+            counts[BodyKind::ALL.iter().position(|k| k == kind).unwrap_or(0)] += 1;
+
+            let orbit_radius = body.semi_axis.major;
+            radius = (
+                radius.0.min(orbit_radius),
+                radius.1.max(orbit_radius),
+                radius.2 + orbit_radius,
+            );
+            speed = (speed.0.min(body.orbit_speed), speed.1.max(body.orbit_speed));
+            body_count += 1;
+        }
+
+        let found = body_count > 0;
+        Self {
+            counts,
+            radius: found.then(|| (radius.0, radius.1, radius.2 / body_count as f32)),
+            speed: found.then_some(speed),
+        }
+    }
+}
+
+pub fn update_stats(
+    time: Res<Time>,
+    mut settings: ResMut<StatsSettings>,
+    configuration: Res<Configuration>,
+    units: Res<MapUnits>,
+    diagnostics: Res<DiagnosticsStore>,
+    q_bodies: Query<(&OrbitingBody, &BodyKind)>,
+    mut text_query: Query<&mut Text, With<StatsText>>,
+) {
+    // -- Ticking must not count as a settings change, which would redraw the panel
+    let ticked = settings
+        .bypass_change_detection()
+        .refresh
+        .tick(time.delta())
+        .just_finished();
+    if !settings.expanded || !(ticked || configuration.is_changed()) {
+        return;
+    }
+
+    let stats = BodyStats::gather(&q_bodies);
+
+    let mut value = String::new();
+    for (kind, count) in BodyKind::ALL.iter().zip(stats.counts) {
+        value.push_str(&format!("{}: {}\n", kind, count));
+    }
+
+    value.push_str(&format!("Orbits: {}\n", configuration.orbit_count()));
+    if let (Some(radius), Some(speed)) = (stats.radius, stats.speed) {
+        value.push_str(&format!(
+            "Orbit radius: {} to {}, mean {}\nOrbit speed: {:.2} to {:.2} rad/s\n",
+            units.format(radius.0),
+            units.format(radius.1),
+            units.format(radius.2),
+            speed.0,
+            speed.1,
+        ));
+    }
+
+    let diagnostic = |path: &DiagnosticPath| {
+        diagnostics
+            .get(path)
+            .and_then(|diagnostic| diagnostic.smoothed())
+    };
+    if let Some(entities) = diagnostic(&EntityCountDiagnosticsPlugin::ENTITY_COUNT) {
+        value.push_str(&format!("Entities: {:.0}\n", entities));
+    }
+    if let Some(frame_time) = diagnostic(&FrameTimeDiagnosticsPlugin::FRAME_TIME) {
+        value.push_str(&format!("Frame time: {:.2} ms", frame_time));
+    }
+
+    for mut text in &mut text_query {
+        text.sections[0].value = value.trim_end().to_string();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn body(major: f32, orbit_speed: f32) -> OrbitingBody {
+        OrbitingBody {
+            angle: 0.0,
+            orbit_speed,
+            semi_axis: SemiAxis { major, minor: major },
+            y_rotation: Quat::IDENTITY,
+            dimming_channel: 1.0,
+            orbit: 0,
+            semi_axis_spread: 1.0,
+            speed_fluctuation: 1.0,
+        }
+    }
+
+    #[test]
+    fn gathers_counts_and_ranges() {
+        let bodies = [
+            (body(1.0, 0.5), BodyKind::Star),
+            (body(2.0, 0.25), BodyKind::Star),
+            (body(6.0, 0.1), BodyKind::Dust),
+            (body(3.0, 0.2), BodyKind::Filament),
+        ];
+        let stats = BodyStats::gather(bodies.iter().map(|(body, kind)| (body, kind)));
+
+        assert_eq!(stats.counts, [2, 1, 0, 1]);
+        assert_eq!(stats.radius, Some((1.0, 6.0, 3.0)));
+        assert_eq!(stats.speed, Some((0.1, 0.5)));
+    }
+
+    #[test]
+    fn no_ranges_without_bodies() {
+        let stats = BodyStats::gather([]);
+
+        assert_eq!(stats.counts, [0; 4]);
+        assert_eq!(stats.radius, None);
+        assert_eq!(stats.speed, None);
+    }
+}
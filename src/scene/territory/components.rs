@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+/// Everything spawned for the territory, despawned when it's turned off
+#[derive(Component)]
+pub struct TerritoryOverlay;
+
+#[derive(Component)]
+pub struct TerritoryMap;
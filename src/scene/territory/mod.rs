@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+use crate::scene::territory::resources::*;
+use crate::scene::territory::systems::*;
+
+pub mod components;
+pub mod resources;
+pub mod systems;
+
+pub struct TerritoryPlugin;
+
+impl Plugin for TerritoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TerritorySettings>()
+            .init_resource::<Territory>()
+            .add_systems(
+                Update,
+                (
+                    toggle_territory,
+                    show_territory.run_if(resource_changed::<TerritorySettings>),
+                    assign_seed_stars.run_if(seed_stars_outdated),
+                    paint_territory.run_if(territory_enabled),
+                )
+                    .chain(),
+            );
+    }
+}
@@ -0,0 +1,83 @@
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// Territory as stored on disk
+#[derive(Deserialize)]
+pub struct TerritoryFile {
+    pub factions: Vec<FactionFile>,
+}
+
+#[derive(Deserialize)]
+pub struct FactionFile {
+    pub name: String,
+    /// sRGB color of the faction's regions
+    pub color: [f32; 3],
+    /// Points of the galactic plane as (x, z), each claimed by the star closest to it
+    pub seeds: Vec<[f32; 2]>,
+}
+
+#[derive(Resource)]
+pub struct TerritorySettings {
+    pub enabled: bool,
+    pub path: PathBuf,
+    /// Side of the territory texture, in pixels
+    pub resolution: u32,
+    /// How often the borders follow the orbiting seed stars
+    pub refresh: Timer,
+}
+
+impl Default for TerritorySettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            path: PathBuf::from("assets/territory.ron"),
+            resolution: 256,
+            refresh: Timer::from_seconds(0.5, TimerMode::Repeating),
+        }
+    }
+}
+
+pub struct Faction {
+    pub name: String,
+    pub color: Color,
+}
+
+pub struct TerritorySeed {
+    pub faction: usize,
+    /// Where the seed was placed in the file
+    pub position: Vec2,
+    /// The star owning the seed in the current galaxy
+    pub star: Option<Entity>,
+}
+
+#[derive(Resource, Default)]
+pub struct Territory {
+    pub factions: Vec<Faction>,
+    pub seeds: Vec<TerritorySeed>,
+}
+
+impl From<TerritoryFile> for Territory {
+    fn from(file: TerritoryFile) -> Self {
+        let mut territory = Territory::default();
+
+        for (index, faction) in file.factions.into_iter().enumerate() {
+            let [red, green, blue] = faction.color;
+
+            territory
+                .seeds
+                .extend(faction.seeds.iter().map(|&[x, z]| TerritorySeed {
+                    faction: index,
+                    position: Vec2::new(x, z),
+                    star: None,
+                }));
+            territory.factions.push(Faction {
+                name: faction.name,
+                color: Color::srgb(red, green, blue),
+            });
+        }
+
+        territory
+    }
+}
@@ -0,0 +1,250 @@
+use std::fs;
+
+use bevy::prelude::*;
+
+use crate::configuration::resources::Configuration;
+use crate::scene::plane_map::*;
+use crate::scene::territory::components::*;
+use crate::scene::territory::resources::*;
+use crate::scene::*;
+use crate::ui::styles::*;
+
+const TERRITORY_TOGGLE_KEY: KeyCode = KeyCode::KeyV;
+/// Opacity of the regions and of the borders between them
+const REGION_ALPHA: f32 = 0.25;
+const BORDER_ALPHA: f32 = 0.9;
+/// Keeps the map just under the bodies
+const TERRITORY_HEIGHT: f32 = -0.02;
+
+pub fn territory_enabled(settings: Res<TerritorySettings>) -> bool {
+    settings.enabled
+}
+
+pub fn toggle_territory(kbd: Res<ButtonInput<KeyCode>>, mut settings: ResMut<TerritorySettings>) {
+    if kbd.just_pressed(TERRITORY_TOGGLE_KEY) {
+        settings.enabled = !settings.enabled;
+    }
+}
+
+/// Loads the territory file every time the overlay is turned on,
+/// so edits to it show up without restarting
+#[allow(clippy::too_many_arguments)]
+pub fn show_territory(
+    mut commands: Commands,
+    settings: Res<TerritorySettings>,
+    configuration: Res<Configuration>,
+    mut territory: ResMut<Territory>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut images: ResMut<Assets<Image>>,
+    asset_server: Res<AssetServer>,
+    overlay_query: Query<Entity, With<TerritoryOverlay>>,
+) {
+    for entity in overlay_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    if !settings.enabled {
+        return;
+    }
+
+    let loaded = fs::read_to_string(&settings.path)
+        .map_err(|error| error.to_string())
+        .and_then(|text| ron::from_str::<TerritoryFile>(&text).map_err(|error| error.to_string()));
+
+    match loaded {
+        Ok(file) => *territory = file.into(),
+        Err(error) => {
+            error!("Failed to load {}: {}", settings.path.display(), error);
+            *territory = Territory::default();
+            return;
+        }
+    }
+
+    spawn_plane_map(
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        &mut images,
+        settings.resolution,
+        configuration.galaxy_radius(),
+        TERRITORY_HEIGHT,
+    )
+    .insert((TerritoryMap, TerritoryOverlay));
+
+    spawn_legend(&mut commands, &territory, &asset_server);
+}
+
+fn spawn_legend(commands: &mut Commands, territory: &Territory, asset_server: &Res<AssetServer>) {
+    let wrapper = NodeBundle {
+        style: LEGEND_STYLE,
+        background_color: UI_BACKGROUND_COLOR,
+        border_radius: UI_BORDER_RADIUS,
+        ..default()
+    };
+
+    commands
+        .spawn((wrapper, TerritoryOverlay))
+        .with_children(|parent| {
+            for faction in &territory.factions {
+                let mut style = get_text_style(asset_server, 16.0);
+                style.color = faction.color;
+
+                parent.spawn(TextBundle::from_section(faction.name.clone(), style));
+            }
+        });
+}
+
+/// Seeds are claimed again when the territory is loaded or the galaxy regenerated
+pub fn seed_stars_outdated(
+    territory: Res<Territory>,
+    added_query: Query<(), Added<OrbitingBody>>,
+) -> bool {
+    territory.is_changed() || !added_query.is_empty()
+}
+
+pub fn assign_seed_stars(
+    mut territory: ResMut<Territory>,
+    q_bodies: Query<(Entity, &OrbitingBody, &BodyKind)>,
+) {
+    let stars: Vec<(Vec2, Entity)> = q_bodies
+        .iter()
+        .filter(|(_, _, kind)| **kind == BodyKind::Star)
+        .map(|(entity, body, _)| (body.position().xz(), entity))
+        .collect();
+
+    for seed in &mut territory.seeds {
+        seed.star = closest(&stars, seed.position);
+    }
+}
+
+pub fn paint_territory(
+    time: Res<Time>,
+    mut settings: ResMut<TerritorySettings>,
+    configuration: Res<Configuration>,
+    territory: Res<Territory>,
+    mut images: ResMut<Assets<Image>>,
+    mut map_query: Query<(&mut PlaneMap, &mut Transform), With<TerritoryMap>>,
+    q_bodies: Query<&GlobalTransform>,
+) {
+    // -- Ticking must not count as a settings change, which would reload the file
+    let ticked = settings
+        .bypass_change_detection()
+        .refresh
+        .tick(time.delta())
+        .just_finished();
+    let Ok((mut map, mut transform)) = map_query.get_single_mut() else {
+        return;
+    };
+    if !ticked && !map.is_added() && !territory.is_changed() {
+        return;
+    }
+
+    // -- The galaxy may have grown or shrunk since the map was made
+    let radius = configuration.galaxy_radius();
+    if map.extent != radius {
+        map.extent = radius;
+        *transform = map.transform(TERRITORY_HEIGHT);
+    }
+
+    // -- Seeds sit on their stars, wherever the orbit has taken them
+    let seeds: Vec<(Vec2, usize)> = territory
+        .seeds
+        .iter()
+        .filter_map(|seed| {
+            let star = q_bodies.get(seed.star?).ok()?;
+
+            Some((star.translation().xz(), seed.faction))
+        })
+        .collect();
+
+    let resolution = map.resolution;
+    let mut owners = vec![None; (resolution * resolution) as usize];
+    for row in 0..resolution {
+        for column in 0..resolution {
+            let position = map.pixel_position(column, row);
+
+            // -- Nothing is claimed outside of the galaxy
+            if position.length() > radius {
+                continue;
+            }
+
+            owners[(row * resolution + column) as usize] = closest(&seeds, position);
+        }
+    }
+
+    let Some(image) = images.get_mut(&map.image) else {
+        return;
+    };
+
+    for row in 0..resolution {
+        for column in 0..resolution {
+            let index = (row * resolution + column) as usize;
+            let pixel = &mut image.data[index * 4..index * 4 + 4];
+
+            let Some(faction) = owners[index] else {
+                pixel.copy_from_slice(&[0, 0, 0, 0]);
+                continue;
+            };
+
+            // -- Pixels next to another owner draw the border
+            let border = [(1, 0), (0, 1)].iter().any(|(dx, dy)| {
+                let (x, y) = (column + dx, row + dy);
+
+                x < resolution
+                    && y < resolution
+                    && owners[(y * resolution + x) as usize] != Some(faction)
+            });
+            let alpha = if border { BORDER_ALPHA } else { REGION_ALPHA };
+
+            let color = territory.factions[faction]
+                .color
+                .to_srgba()
+                .with_alpha(alpha);
+            pixel.copy_from_slice(&color.to_u8_array());
+        }
+    }
+}
+
+/// What's attached to the point closest to `position`
+fn closest<T: Copy>(points: &[(Vec2, T)], position: Vec2) -> Option<T> {
+    points
+        .iter()
+        .min_by(|a, b| {
+            let a = a.0.distance_squared(position);
+            let b = b.0.distance_squared(position);
+
+            a.total_cmp(&b)
+        })
+        .map(|(_, value)| *value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closest_point_claims() {
+        let seeds = [(Vec2::new(-4.0, 0.0), 0), (Vec2::new(4.0, 0.0), 1)];
+
+        assert_eq!(closest(&seeds, Vec2::new(-1.0, 3.0)), Some(0));
+        assert_eq!(closest(&seeds, Vec2::new(1.0, -3.0)), Some(1));
+        assert_eq!(closest::<usize>(&[], Vec2::ZERO), None);
+    }
+
+    #[test]
+    fn shipped_territory_loads() {
+        let text = include_str!("../../../assets/territory.ron");
+        let file: TerritoryFile = ron::from_str(text).unwrap();
+        let seeds: usize = file.factions.iter().map(|faction| faction.seeds.len()).sum();
+        let territory = Territory::from(file);
+
+        assert_eq!(territory.factions.len(), 4);
+        assert_eq!(territory.seeds.len(), seeds);
+        // -- Seeds keep the index of the faction they were listed under
+        assert_eq!(territory.seeds[0].faction, 0);
+        assert_eq!(territory.seeds[seeds - 1].faction, 3);
+        assert_eq!(territory.seeds[1].position, Vec2::new(2.5, -1.5));
+        assert!(territory.seeds.iter().all(|seed| seed.star.is_none()));
+    }
+}
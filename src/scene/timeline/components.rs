@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+
+#[derive(Component, Clone, Copy)]
+pub enum TimelineButton {
+    Back,
+    Forward,
+    SetKeyframe,
+    RemoveKeyframe,
+    Play,
+    Interpolation,
+    Clear,
+}
+
+impl TimelineButton {
+    pub const ALL: [TimelineButton; 7] = [
+        TimelineButton::Back,
+        TimelineButton::Forward,
+        TimelineButton::SetKeyframe,
+        TimelineButton::RemoveKeyframe,
+        TimelineButton::Play,
+        TimelineButton::Interpolation,
+        TimelineButton::Clear,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TimelineButton::Back => "-1 s",
+            TimelineButton::Forward => "+1 s",
+            TimelineButton::SetKeyframe => "Set Key",
+            TimelineButton::RemoveKeyframe => "Remove Key",
+            TimelineButton::Play => "Play/Pause",
+            TimelineButton::Interpolation => "Easing",
+            TimelineButton::Clear => "Clear",
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct TimelineText;
+
+/// Number field showing the playhead, typing a time moves the playhead there
+#[derive(Component)]
+pub struct PlayheadField;
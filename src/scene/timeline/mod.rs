@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+use crate::scene::timeline::resources::Timeline;
+use crate::scene::timeline::systems::*;
+
+pub mod components;
+pub mod resources;
+pub mod systems;
+
+pub struct TimelinePlugin;
+
+impl Plugin for TimelinePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Timeline>()
+            .add_systems(Startup, spawn_timeline_panel)
+            .add_systems(
+                Update,
+                (
+                    toggle_playback,
+                    press_timeline_buttons,
+                    submit_playhead_field,
+                    play_timeline,
+                    show_timeline.run_if(resource_changed::<Timeline>),
+                    show_playhead,
+                )
+                    .chain(),
+            );
+    }
+}
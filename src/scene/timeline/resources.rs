@@ -0,0 +1,216 @@
+use bevy::prelude::*;
+
+use crate::configuration::parameters::PARAMETERS;
+use crate::configuration::resources::Configuration;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Interpolation {
+    Linear,
+    /// Eases in and out of every keyframe
+    Smooth,
+}
+
+impl Interpolation {
+    pub fn label(self) -> &'static str {
+        match self {
+            Interpolation::Linear => "linear",
+            Interpolation::Smooth => "smooth",
+        }
+    }
+
+    fn ease(self, t: f32) -> f32 {
+        match self {
+            Interpolation::Linear => t,
+            Interpolation::Smooth => t * t * (3.0 - 2.0 * t),
+        }
+    }
+}
+
+pub struct Keyframe {
+    /// Seconds from the start of the timeline
+    pub time: f32,
+    pub configuration: Configuration,
+}
+
+#[derive(Resource)]
+pub struct Timeline {
+    /// Sorted by time
+    pub keyframes: Vec<Keyframe>,
+    /// Playhead, in seconds
+    pub time: f32,
+    pub playing: bool,
+    pub interpolation: Interpolation,
+    /// How often the views following the bodies catch up while playing
+    pub refresh: Timer,
+}
+
+impl Default for Timeline {
+    fn default() -> Self {
+        Self {
+            keyframes: Vec::new(),
+            time: 0.0,
+            playing: false,
+            interpolation: Interpolation::Smooth,
+            refresh: Timer::from_seconds(0.5, TimerMode::Repeating),
+        }
+    }
+}
+
+impl Timeline {
+    /// Keyframes closer than this are the same keyframe
+    const TIME_EPSILON: f32 = 1e-3;
+
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map_or(0.0, |keyframe| keyframe.time)
+    }
+
+    fn keyframe_at_playhead(&self) -> Result<usize, usize> {
+        self.keyframes.binary_search_by(|keyframe| {
+            if (keyframe.time - self.time).abs() < Self::TIME_EPSILON {
+                std::cmp::Ordering::Equal
+            } else {
+                keyframe.time.total_cmp(&self.time)
+            }
+        })
+    }
+
+    /// Keeps `configuration` at the playhead, replacing the keyframe there
+    pub fn set_keyframe(&mut self, configuration: &Configuration) {
+        let keyframe = Keyframe {
+            time: self.time,
+            configuration: configuration.clone(),
+        };
+
+        match self.keyframe_at_playhead() {
+            Ok(index) => self.keyframes[index] = keyframe,
+            Err(index) => self.keyframes.insert(index, keyframe),
+        }
+    }
+
+    pub fn remove_keyframe(&mut self) {
+        if let Ok(index) = self.keyframe_at_playhead() {
+            self.keyframes.remove(index);
+        }
+    }
+
+    /// Configuration at the playhead. Continuous parameters are interpolated,
+    /// the others keep the value of the previous keyframe.
+    pub fn sample(&self) -> Option<Configuration> {
+        let next = self
+            .keyframes
+            .iter()
+            .position(|keyframe| keyframe.time > self.time);
+
+        let (from, to) = match next {
+            None => return self.keyframes.last().map(|k| k.configuration.clone()),
+            Some(0) => return Some(self.keyframes[0].configuration.clone()),
+            Some(next) => (&self.keyframes[next - 1], &self.keyframes[next]),
+        };
+
+        let t = (self.time - from.time) / (to.time - from.time);
+        let t = self.interpolation.ease(t.clamp(0.0, 1.0));
+
+        let mut configuration = from.configuration.clone();
+        for parameter in PARAMETERS.iter().filter(|parameter| parameter.continuous) {
+            let start = parameter.value(&from.configuration);
+            let end = parameter.value(&to.configuration);
+
+            parameter.apply(&mut configuration, start + (end - start) * t);
+        }
+
+        Some(configuration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keyframe(time: f32, velocity: f32, star_count: u32) -> Keyframe {
+        Keyframe {
+            time,
+            configuration: Configuration {
+                velocity,
+                star_count,
+                ..default()
+            },
+        }
+    }
+
+    /// Keyframes at 1 s and 3 s, velocity going from 1 to 3
+    fn timeline(interpolation: Interpolation) -> Timeline {
+        Timeline {
+            keyframes: vec![keyframe(1.0, 1.0, 6_000), keyframe(3.0, 3.0, 8_000)],
+            interpolation,
+            ..default()
+        }
+    }
+
+    fn sample_at(timeline: &mut Timeline, time: f32) -> Configuration {
+        timeline.time = time;
+
+        timeline.sample().unwrap()
+    }
+
+    #[test]
+    fn nothing_without_keyframes() {
+        assert!(Timeline::default().sample().is_none());
+    }
+
+    #[test]
+    fn outside_the_keyframes() {
+        let mut timeline = timeline(Interpolation::Linear);
+
+        let before = sample_at(&mut timeline, 0.0);
+        assert_eq!(before, timeline.keyframes[0].configuration);
+
+        let after = sample_at(&mut timeline, 5.0);
+        assert_eq!(after, timeline.keyframes[1].configuration);
+    }
+
+    #[test]
+    fn between_keyframes() {
+        let mut timeline = timeline(Interpolation::Linear);
+
+        assert_eq!(sample_at(&mut timeline, 1.0).velocity, 1.0);
+        assert_eq!(sample_at(&mut timeline, 1.5).velocity, 1.5);
+        assert_eq!(sample_at(&mut timeline, 2.0).velocity, 2.0);
+        assert_eq!(sample_at(&mut timeline, 3.0).velocity, 3.0);
+    }
+
+    #[test]
+    fn smooth_easing() {
+        let mut timeline = timeline(Interpolation::Smooth);
+
+        // -- Slower than linear near a keyframe, the same halfway
+        assert_eq!(sample_at(&mut timeline, 1.5).velocity, 1.3125);
+        assert_eq!(sample_at(&mut timeline, 2.0).velocity, 2.0);
+        assert_eq!(sample_at(&mut timeline, 2.5).velocity, 2.6875);
+    }
+
+    #[test]
+    fn discrete_fields_keep_the_previous_keyframe() {
+        let mut timeline = timeline(Interpolation::Linear);
+
+        assert_eq!(sample_at(&mut timeline, 2.9).star_count, 6_000);
+        assert_eq!(sample_at(&mut timeline, 3.0).star_count, 8_000);
+    }
+
+    #[test]
+    fn keyframes_are_kept_sorted() {
+        let mut timeline = Timeline::default();
+        for time in [2.0, 0.5, 1.25] {
+            timeline.time = time;
+            timeline.set_keyframe(&Configuration::default());
+        }
+        // -- Replaces the keyframe already there
+        timeline.set_keyframe(&Configuration::default());
+
+        let times: Vec<f32> = timeline.keyframes.iter().map(|k| k.time).collect();
+        assert_eq!(times, [0.5, 1.25, 2.0]);
+
+        timeline.time = 0.5;
+        timeline.remove_keyframe();
+        assert_eq!(timeline.keyframes.len(), 2);
+    }
+}
@@ -0,0 +1,249 @@
+use bevy::prelude::*;
+
+use crate::configuration::parameters::same_bodies;
+use crate::configuration::resources::Configuration;
+use crate::scene::orbiting_body::events::BodiesReshaped;
+use crate::scene::orbiting_body::systems::reshape_body;
+use crate::scene::timeline::components::*;
+use crate::scene::timeline::resources::*;
+use crate::scene::*;
+use crate::ui::components::TextInput;
+use crate::ui::events::TextSubmitted;
+use crate::ui::resources::InputFocus;
+use crate::ui::styles::*;
+use crate::ui::systems::spawn_text_input;
+
+const PLAYBACK_KEY: KeyCode = KeyCode::KeyP;
+/// How far the playhead moves with the step buttons, in seconds
+const TIME_STEP: f32 = 1.0;
+
+pub fn spawn_timeline_panel(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let wrapper = NodeBundle {
+        style: TIMELINE_PANEL_STYLE,
+        background_color: UI_BACKGROUND_COLOR,
+        border_radius: UI_BORDER_RADIUS,
+        ..default()
+    };
+    let text = TextBundle::from_section("", get_text_style(&asset_server, 16.0));
+    let toolbar = NodeBundle {
+        style: TOOLBAR_STYLE,
+        ..default()
+    };
+
+    // -- Interaction lets clicks on the panel be told apart from clicks on the map
+    commands
+        .spawn((wrapper, Interaction::default()))
+        .with_children(|parent| {
+            parent.spawn((text, TimelineText));
+            parent.spawn(toolbar).with_children(|parent| {
+                spawn_text_input(
+                    parent,
+                    &asset_server,
+                    &format_playhead(0.0),
+                    "",
+                    NUMBER_FIELD_STYLE,
+                    PlayheadField,
+                );
+                for button in TimelineButton::ALL {
+                    let node = NodeBundle {
+                        style: TOOLBAR_BUTTON_STYLE,
+                        border_color: UI_BORDER_COLOR,
+                        border_radius: UI_BORDER_RADIUS,
+                        background_color: ELEMENT_BACKGROUND_COLOR,
+                        ..default()
+                    };
+
+                    parent
+                        .spawn((node, button, Interaction::default()))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                button.label(),
+                                get_text_style(&asset_server, 14.0),
+                            ));
+                        });
+                }
+            });
+        });
+}
+
+pub fn toggle_playback(
+    kbd: Res<ButtonInput<KeyCode>>,
+    mut timeline: ResMut<Timeline>,
+    mut config: ResMut<Configuration>,
+) {
+    if kbd.just_pressed(PLAYBACK_KEY) {
+        let playing = !timeline.playing;
+        set_playing(&mut timeline, &mut config, playing);
+    }
+}
+
+pub fn press_timeline_buttons(
+    mut interaction_query: Query<
+        (&TimelineButton, &Interaction, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    mut timeline: ResMut<Timeline>,
+    mut config: ResMut<Configuration>,
+) {
+    for (button, interaction, mut background_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *background_color = ELEMENT_BACKGROUND_COLOR_PRESSED;
+
+                match button {
+                    TimelineButton::Back | TimelineButton::Forward => {
+                        let step = match button {
+                            TimelineButton::Back => -TIME_STEP,
+                            _ => TIME_STEP,
+                        };
+                        let time = timeline.time + step;
+                        move_playhead(&mut timeline, &mut config, time);
+                    }
+                    TimelineButton::SetKeyframe => timeline.set_keyframe(&config),
+                    TimelineButton::RemoveKeyframe => timeline.remove_keyframe(),
+                    TimelineButton::Play => {
+                        let playing = !timeline.playing;
+                        set_playing(&mut timeline, &mut config, playing);
+                    }
+                    TimelineButton::Interpolation => {
+                        timeline.interpolation = match timeline.interpolation {
+                            Interpolation::Linear => Interpolation::Smooth,
+                            Interpolation::Smooth => Interpolation::Linear,
+                        };
+                    }
+                    TimelineButton::Clear => {
+                        set_playing(&mut timeline, &mut config, false);
+                        *timeline = Timeline::default();
+                    }
+                }
+            }
+            Interaction::Hovered => *background_color = ELEMENT_BACKGROUND_COLOR_HOVERED,
+            Interaction::None => *background_color = ELEMENT_BACKGROUND_COLOR,
+        }
+    }
+}
+
+pub fn submit_playhead_field(
+    mut evr_submitted: EventReader<TextSubmitted>,
+    field_query: Query<&TextInput, With<PlayheadField>>,
+    mut focus: ResMut<InputFocus>,
+    mut timeline: ResMut<Timeline>,
+    mut config: ResMut<Configuration>,
+) {
+    for ev in evr_submitted.read() {
+        let Ok(input) = field_query.get(ev.entity) else {
+            continue;
+        };
+
+        // -- Anything but a time is dropped, `show_playhead` puts the playhead back
+        let time = input.value.trim().parse::<f32>().ok();
+        if let Some(time) = time.filter(|time| time.is_finite()) {
+            move_playhead(&mut timeline, &mut config, time);
+        }
+
+        focus.entity = None;
+    }
+}
+
+pub fn play_timeline(
+    time: Res<Time>,
+    mut timeline: ResMut<Timeline>,
+    mut config: ResMut<Configuration>,
+    mut q_bodies: Query<&mut OrbitingBody>,
+    mut evw_reshaped: EventWriter<BodiesReshaped>,
+) {
+    if !timeline.playing {
+        return;
+    }
+
+    // -- Playing from the end starts over
+    if timeline.time >= timeline.duration() {
+        timeline.time = 0.0;
+    }
+    timeline.time = (timeline.time + time.delta_seconds()).min(timeline.duration());
+
+    let Some(sampled) = timeline.sample() else {
+        return;
+    };
+
+    if !same_bodies(&config, &sampled) {
+        // -- A keyframe changed what's generated, nothing to animate there
+        config.set_if_neq(sampled);
+    } else if *config != sampled {
+        // -- Reshaped here every frame, regenerating the panel and
+        // recording history that often would be wasted work
+        *config.bypass_change_detection() = sampled;
+        for mut body in &mut q_bodies {
+            reshape_body(&mut body, &config);
+        }
+        // -- The views built from the bodies only catch up now and then
+        if timeline.refresh.tick(time.delta()).just_finished() {
+            evw_reshaped.send(BodiesReshaped);
+        }
+    }
+
+    if timeline.time >= timeline.duration() {
+        set_playing(&mut timeline, &mut config, false);
+    }
+}
+
+pub fn show_timeline(
+    timeline: Res<Timeline>,
+    mut text_query: Query<&mut Text, With<TimelineText>>,
+) {
+    let state = if timeline.playing { "playing" } else { "paused" };
+    let value = format!(
+        "Timeline: {:.1} s of {:.1} s, {}\n{} keyframes, {} easing",
+        timeline.time,
+        timeline.duration(),
+        state,
+        timeline.keyframes.len(),
+        timeline.interpolation.label(),
+    );
+
+    for mut text in &mut text_query {
+        text.sections[0].value = value.clone();
+    }
+}
+
+/// Keeps the playhead field up to date, unless it's being typed into
+pub fn show_playhead(
+    focus: Res<InputFocus>,
+    timeline: Res<Timeline>,
+    mut field_query: Query<(Entity, &mut TextInput), With<PlayheadField>>,
+) {
+    let value = format_playhead(timeline.time);
+
+    for (entity, mut input) in &mut field_query {
+        if focus.entity != Some(entity) && input.value != value {
+            input.value = value.clone();
+        }
+    }
+}
+
+fn format_playhead(time: f32) -> String {
+    format!("{time:.2}")
+}
+
+/// Pauses at `time`, showing the galaxy there
+fn move_playhead(timeline: &mut ResMut<Timeline>, config: &mut ResMut<Configuration>, time: f32) {
+    set_playing(timeline, config, false);
+    timeline.time = time.max(0.0);
+
+    // -- Scrubbing shows the galaxy at the playhead
+    if let Some(sampled) = timeline.sample() {
+        config.set_if_neq(sampled);
+    }
+}
+
+fn set_playing(timeline: &mut ResMut<Timeline>, config: &mut ResMut<Configuration>, playing: bool) {
+    // -- Needs two keyframes to move between
+    let playing = playing && timeline.keyframes.len() > 1;
+
+    // -- Lets the panel and the history catch up with the animated values
+    if timeline.playing && !playing {
+        config.set_changed();
+    }
+
+    timeline.playing = playing;
+}
@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
+use crate::configuration::parameters::Parameter;
+
 #[derive(Component)]
 pub struct UI;
 
@@ -14,43 +16,80 @@ pub enum CheckboxKind {
     Dust,
     HII,
     Filament,
+    Grid,
+    ShowStars,
+    ShowDust,
+    ShowHII,
+    ShowFilaments,
+    ShowCenter,
 }
 
 #[derive(Component)]
 pub struct Marker; 
 
-#[derive(Component, Clone, Copy)]
-pub struct TweakButton {
-    pub action: ButtonAction,
-    pub kind: ButtonKind,
+/// Draggable control for a configuration field
+#[derive(Component)]
+pub struct Slider {
+    pub parameter: &'static Parameter,
+    /// Value under the handle, applied when the drag is released
+    pub value: f32,
+    pub dragging: bool,
 }
 
-#[derive(Component, Clone, Copy)]
-pub enum ButtonAction {
-    Increment,
-    Decrement,
+#[derive(Component)]
+pub struct SliderHandle;
+
+/// Keeps a configuration field out of "Surprise Me" and "Nudge"
+#[derive(Component)]
+pub struct ParameterLock {
+    pub parameter: &'static Parameter,
 }
 
-#[derive(Component, Copy, Clone)]
-pub enum ButtonKind {
-    StarCount,
-    StarSize,
-    Velocity,
-    SemiAxis,
-    OrbitDensity,
-    OrbitRotation,
+/// Text input for a configuration field, applied when Enter is pressed
+#[derive(Component)]
+pub struct NumberField {
+    pub parameter: &'static Parameter,
 }
 
-impl ToString for ButtonKind {
-    fn to_string(&self) -> String {
+#[derive(Component, Clone, Copy)]
+pub enum ToolbarButton {
+    Undo,
+    Redo,
+    Reseed,
+    CopyCode,
+    PasteCode,
+    SurpriseMe,
+    Nudge,
+}
+
+impl ToolbarButton {
+    pub fn label(self) -> &'static str {
         match self {
-            ButtonKind::StarCount => "Star Count".to_string(),
-            ButtonKind::StarSize => "Star Size".to_string(),
-            ButtonKind::Velocity => "Velocity".to_string(),
-            ButtonKind::SemiAxis => "Semi Axis".to_string(),
-            ButtonKind::OrbitDensity => "Orbit Density".to_string(),
-            ButtonKind::OrbitRotation => "Orbit Rotation".to_string(),
+            ToolbarButton::Undo => "Undo",
+            ToolbarButton::Redo => "Redo",
+            ToolbarButton::Reseed => "New Seed",
+            ToolbarButton::CopyCode => "Copy Code",
+            ToolbarButton::PasteCode => "Paste Code",
+            ToolbarButton::SurpriseMe => "Surprise Me",
+            ToolbarButton::Nudge => "Nudge",
         }
     }
 }
 
+/// Single-line text field, typed into while it has the input focus
+#[derive(Component, Default)]
+pub struct TextInput {
+    pub value: String,
+    /// Shown greyed out while the field is empty
+    pub placeholder: String,
+}
+
+/// Text child displaying a `TextInput`'s value
+#[derive(Component)]
+pub struct TextInputText;
+
+/// Message shown by `ShowNotice`, removed once its timer finishes
+#[derive(Component)]
+pub struct Notice {
+    pub timer: Timer,
+}
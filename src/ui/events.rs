@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+/// Sent when Enter is pressed in a text input, whose value is in its `TextInput`
+#[derive(Event)]
+pub struct TextSubmitted {
+    pub entity: Entity,
+}
+
+/// Shows a short message at the bottom of the screen
+#[derive(Event)]
+pub struct ShowNotice {
+    pub message: String,
+}
@@ -1,8 +1,14 @@
+use bevy::input::InputSystem;
 use bevy::prelude::*;
+use bevy::render::view::VisibilitySystems;
 
 use crate::{configuration::resources::Configuration, ui::systems::*};
+use crate::ui::events::*;
+use crate::ui::resources::*;
 
 pub mod components;
+pub mod events;
+pub mod resources;
 pub mod styles;
 pub mod systems;
 
@@ -10,7 +16,6 @@ pub mod systems;
 pub enum UIState {
     #[default]
     Displayed,
-    #[allow(dead_code)]
     Hidden,
 }
 
@@ -19,18 +24,40 @@ pub struct UIPlugin;
 impl Plugin for UIPlugin {
     fn build(&self, app: &mut App) {
         app.init_state::<UIState>()
+            .init_resource::<InputFocus>()
+            .init_non_send_resource::<GalaxyClipboard>()
+            .add_event::<TextSubmitted>()
+            .add_event::<ShowNotice>()
             .add_systems(Startup, build_ui)
-            // -- Note --
-            // Displaying and hiding UI is not finished
+            .add_systems(PreUpdate, block_shortcuts_while_typing.after(InputSystem))
+            .add_systems(
+                PostUpdate,
+                hide_ui
+                    .run_if(in_state(UIState::Hidden))
+                    .before(VisibilitySystems::VisibilityPropagate),
+            )
+            .add_systems(OnExit(UIState::Hidden), show_ui)
             .add_systems(
                 Update,
                 (
+                    toggle_ui,
                     interact_with_checkbox.run_if(in_state(UIState::Displayed)),
-                    interact_with_button.run_if(in_state(UIState::Displayed)),
+                    interact_with_toolbar.run_if(in_state(UIState::Displayed)),
+                    toggle_parameter_lock.run_if(in_state(UIState::Displayed)),
+                    drag_slider.run_if(in_state(UIState::Displayed)),
                     // -- Note --
                     // Not a great way to update UI by rebuilding it from scratch
                     // but more concise way required more work
                     rebuild_ui.run_if(resource_changed::<Configuration>),
+                    (show_notice, expire_notice),
+                    (
+                        focus_text_input,
+                        type_into_text_input,
+                        submit_number_field,
+                        revert_number_fields.run_if(resource_changed::<InputFocus>),
+                        display_text_input,
+                    )
+                        .chain(),
                 ),
             );
     }
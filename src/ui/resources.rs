@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+
+/// Text input receiving the keyboard, if any.
+/// Keyboard shortcuts are blocked while it's set.
+#[derive(Resource, Default)]
+pub struct InputFocus {
+    pub entity: Option<Entity>,
+}
+
+/// System clipboard, opened on first use. On Linux its content
+/// only lasts as long as it's kept open.
+#[derive(Default)]
+pub struct GalaxyClipboard {
+    clipboard: Option<arboard::Clipboard>,
+}
+
+impl GalaxyClipboard {
+    pub fn get(&mut self) -> Result<&mut arboard::Clipboard, arboard::Error> {
+        if self.clipboard.is_none() {
+            self.clipboard = Some(arboard::Clipboard::new()?);
+        }
+
+        Ok(self.clipboard.as_mut().unwrap())
+    }
+}
@@ -8,6 +8,9 @@ pub const CHECKBOX_BACKGROUND_COLOR_CHECKED: BackgroundColor = BackgroundColor(C
 pub const CHECKBOX_BORDER_COLOR_CHECKED: BorderColor = BorderColor(Color::WHITE);
 pub const ELEMENT_BACKGROUND_COLOR: BackgroundColor = BackgroundColor(Color::srgba(0.2, 0.2, 0.2, 0.5));
 pub const ELEMENT_BACKGROUND_COLOR_HOVERED: BackgroundColor = BackgroundColor(Color::srgba(0.3, 0.3, 0.3, 0.5));
+pub const TEXT_INPUT_BORDER_COLOR: BorderColor = BorderColor(Color::srgba(1.0, 1.0, 1.0, 0.4));
+pub const SLIDER_HANDLE_COLOR: BackgroundColor = BackgroundColor(Color::srgba(0.9, 0.9, 0.9, 0.9));
+pub const TEXT_INPUT_PLACEHOLDER_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.4);
 pub const ELEMENT_BACKGROUND_COLOR_PRESSED: BackgroundColor = BackgroundColor(Color::srgba(0.4, 0.4, 0.4, 0.5));
 
 // -- Styles
@@ -73,6 +76,32 @@ pub const FIELD_WRAPPER_STYLE: Style = {
 
     style
 };
+pub const TOOLBAR_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+
+    style.width = Val::Percent(100.0);
+    style.flex_direction = FlexDirection::Row;
+    style.flex_wrap = FlexWrap::Wrap;
+    style.column_gap = Val::Px(6.0);
+    style.row_gap = Val::Px(4.0);
+
+    style
+};
+pub const TOOLBAR_BUTTON_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+
+    style.justify_content = JustifyContent::Center;
+    style.align_items = AlignItems::Center;
+    style.padding = UiRect {
+        left: Val::Px(6.0),
+        right: Val::Px(6.0),
+        top: Val::Px(2.0),
+        bottom: Val::Px(2.0),
+    };
+    style.border = UiRect::all(Val::Px(1.0));
+
+    style
+};
 pub const CHECKBOX_STYLE: Style = {
     let mut style = Style::DEFAULT;
 
@@ -83,37 +112,237 @@ pub const CHECKBOX_STYLE: Style = {
 
     style
 };
-pub const BUTTON_WRAPPER_STYLE: Style = {
+
+pub const FIELD_LABEL_STYLE: Style = {
     let mut style = Style::DEFAULT;
 
-    style.width = Val::Px(24.0);
-    style.height = Val::Px(24.0);
-    style.flex_direction = FlexDirection::Column;
+    style.flex_grow = 1.0;
+
+    style
+};
+pub const NUMBER_FIELD_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+
+    style.width = Val::Px(64.0);
+    style.margin = UiRect {
+        right: Val::Px(8.0),
+        ..UiRect::DEFAULT
+    };
+    style.padding = UiRect {
+        left: Val::Px(4.0),
+        right: Val::Px(4.0),
+        top: Val::Px(2.0),
+        bottom: Val::Px(2.0),
+    };
+    style.border = UiRect::all(Val::Px(1.0));
 
     style
 };
-pub const BUTTON_STYLE: Style = {
+pub const SLIDER_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+
+    style.width = Val::Px(90.0);
+    style.height = Val::Px(16.0);
+    style.align_items = AlignItems::Center;
+
+    style
+};
+pub const SLIDER_TRACK_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+
+    style.width = Val::Percent(100.0);
+    style.height = Val::Px(4.0);
+
+    style
+};
+pub const SLIDER_HANDLE_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+
+    style.position_type = PositionType::Absolute;
+    style.width = Val::Px(8.0);
+    style.height = Val::Px(16.0);
+    // -- Centers the handle on its value
+    style.margin = UiRect {
+        left: Val::Px(-4.0),
+        ..UiRect::DEFAULT
+    };
+
+    style
+};
+pub const LOCK_STYLE: Style = {
     let mut style = Style::DEFAULT;
 
     style.justify_content = JustifyContent::Center;
     style.align_items = AlignItems::Center;
+    style.width = Val::Px(18.0);
+    style.height = Val::Px(18.0);
+    style.margin = UiRect {
+        left: Val::Px(8.0),
+        ..UiRect::DEFAULT
+    };
+
+    style
+};
+pub const GRID_LABEL_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+
+    style.position_type = PositionType::Absolute;
+
+    style
+};
+pub const SCALE_BAR_WRAPPER_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+
+    style.position_type = PositionType::Absolute;
+    style.left = Val::Px(16.0);
+    style.bottom = Val::Px(16.0);
+    style.flex_direction = FlexDirection::Column;
+    style.align_items = AlignItems::FlexStart;
+
+    style
+};
+pub const SCALE_BAR_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+
+    style.height = Val::Px(6.0);
+    style.border = UiRect {
+        left: Val::Px(2.0),
+        right: Val::Px(2.0),
+        bottom: Val::Px(2.0),
+        top: Val::Px(0.0),
+    };
+
+    style
+};
+pub const TOOL_PANEL_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+
+    style.position_type = PositionType::Absolute;
+    style.right = Val::Px(16.0);
+    style.top = Val::Px(16.0);
+    style.padding = UiRect::all(Val::Px(10.0));
+
+    style
+};
+pub const LEGEND_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+
+    style.position_type = PositionType::Absolute;
+    style.right = Val::Px(16.0);
+    style.bottom = Val::Percent(30.0);
+    style.flex_direction = FlexDirection::Column;
+    style.padding = UiRect::all(Val::Px(10.0));
+
+    style
+};
+pub const HEATMAP_LEGEND_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+
+    style.position_type = PositionType::Absolute;
+    style.left = Val::Px(16.0);
+    style.bottom = Val::Px(56.0);
+    style.padding = UiRect::all(Val::Px(10.0));
+    style.flex_direction = FlexDirection::Column;
+    style.row_gap = Val::Px(6.0);
+
+    style
+};
+pub const HEATMAP_SWATCH_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+
+    style.width = Val::Px(20.0);
+    style.height = Val::Px(8.0);
+
+    style
+};
+
+pub const TEXT_INPUT_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+
     style.width = Val::Percent(100.0);
-    style.height = Val::Percent(50.0);
+    style.padding = UiRect {
+        left: Val::Px(8.0),
+        right: Val::Px(8.0),
+        top: Val::Px(4.0),
+        bottom: Val::Px(4.0),
+    };
+    style.border = UiRect::all(Val::Px(1.0));
 
     style
 };
-pub const BUTTON_VALUE_STYLE: Style = {
+
+pub const SEARCH_PANEL_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+
+    style.position_type = PositionType::Absolute;
+    style.left = Val::Percent(35.0);
+    style.top = Val::Px(16.0);
+    style.width = Val::Percent(30.0);
+    style.padding = UiRect::all(Val::Px(10.0));
+    style.flex_direction = FlexDirection::Column;
+    style.row_gap = Val::Px(6.0);
+
+    style
+};
+pub const SEARCH_RESULT_LIST_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+
+    style.flex_direction = FlexDirection::Column;
+    style.row_gap = Val::Px(2.0);
+
+    style
+};
+pub const SEARCH_RESULT_STYLE: Style = {
     let mut style = Style::DEFAULT;
 
-    style.flex_grow = 1.0;
     style.padding = UiRect {
-        right: Val::Px(10.0),
-        ..UiRect::all(Val::Auto)
+        left: Val::Px(8.0),
+        right: Val::Px(8.0),
+        top: Val::Px(2.0),
+        bottom: Val::Px(2.0),
     };
 
     style
 };
 
+pub const STATS_PANEL_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+
+    style.position_type = PositionType::Absolute;
+    style.left = Val::Percent(23.0);
+    style.top = Val::Px(16.0);
+    style.padding = UiRect::all(Val::Px(10.0));
+    style.flex_direction = FlexDirection::Column;
+    style.row_gap = Val::Px(6.0);
+
+    style
+};
+pub const NOTICE_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+
+    style.position_type = PositionType::Absolute;
+    style.left = Val::Percent(30.0);
+    style.bottom = Val::Px(24.0);
+    style.width = Val::Percent(40.0);
+    style.padding = UiRect::all(Val::Px(10.0));
+    style.justify_content = JustifyContent::Center;
+
+    style
+};
+pub const TIMELINE_PANEL_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+
+    style.position_type = PositionType::Absolute;
+    style.right = Val::Percent(2.0);
+    style.bottom = Val::Px(16.0);
+    style.width = Val::Percent(22.0);
+    style.padding = UiRect::all(Val::Px(10.0));
+    style.flex_direction = FlexDirection::Column;
+    style.row_gap = Val::Px(6.0);
+
+    style
+};
+
 // -- Helpers
 pub fn get_text_style(asset_server: &Res<AssetServer>, font_size: f32) -> TextStyle {
     TextStyle {
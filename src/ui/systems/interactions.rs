@@ -1,8 +1,19 @@
 use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use rand::prelude::*;
 
+use crate::configuration::code::*;
+use crate::configuration::events::HistoryStep;
+use crate::configuration::parameters::PARAMETERS;
 use crate::configuration::resources::*;
+use crate::configuration::validation::ConfigurationError;
+use crate::scene::grid::resources::GridSettings;
+use crate::scene::layers::LayerVisibility;
 use crate::ui::components::*;
+use crate::ui::events::*;
+use crate::ui::resources::*;
 use crate::ui::styles::*;
+use crate::ui::systems::slider_percent;
 
 pub fn interact_with_checkbox(
     mut interaction_query: Query<
@@ -17,6 +28,8 @@ pub fn interact_with_checkbox(
     >,
     mut text_query: Query<&mut Visibility, With<Marker>>,
     mut config: ResMut<Configuration>,
+    mut grid_settings: ResMut<GridSettings>,
+    mut layers: ResMut<LayerVisibility>,
 ) {
     for (mut checkbox, interaction, mut background_color, mut border_color, children) in
         &mut interaction_query
@@ -29,6 +42,12 @@ pub fn interact_with_checkbox(
                     CheckboxKind::Dust => config.dust = checkbox.checked,
                     CheckboxKind::HII => config.h2 = checkbox.checked,
                     CheckboxKind::Filament => config.filament = checkbox.checked,
+                    CheckboxKind::Grid => grid_settings.enabled = checkbox.checked,
+                    CheckboxKind::ShowStars => layers.stars = checkbox.checked,
+                    CheckboxKind::ShowDust => layers.dust = checkbox.checked,
+                    CheckboxKind::ShowHII => layers.hii = checkbox.checked,
+                    CheckboxKind::ShowFilaments => layers.filaments = checkbox.checked,
+                    CheckboxKind::ShowCenter => layers.center = checkbox.checked,
                 }
             }
             _ => {}
@@ -54,26 +73,303 @@ pub fn interact_with_checkbox(
     }
 }
 
-pub fn interact_with_button(
+#[allow(clippy::too_many_arguments)]
+pub fn interact_with_toolbar(
     mut interaction_query: Query<
-        (&Interaction, &mut BackgroundColor, &TweakButton),
+        (&ToolbarButton, &Interaction, &mut BackgroundColor),
         Changed<Interaction>,
     >,
+    mut evw_step: EventWriter<HistoryStep>,
+    mut evw_notice: EventWriter<ShowNotice>,
+    mut clipboard: NonSendMut<GalaxyClipboard>,
+    locks: Res<ParameterLocks>,
     mut config: ResMut<Configuration>,
 ) {
-    for (interaction, mut background_color, &button) in &mut interaction_query {
+    for (button, interaction, mut background_color) in &mut interaction_query {
         match *interaction {
             Interaction::Pressed => {
-                *background_color = ELEMENT_BACKGROUND_COLOR_PRESSED.into();
-                config.update(button.kind, button.action);
+                *background_color = ELEMENT_BACKGROUND_COLOR_PRESSED;
+
+                match button {
+                    ToolbarButton::Undo => {
+                        evw_step.send(HistoryStep::Undo);
+                    }
+                    ToolbarButton::Redo => {
+                        evw_step.send(HistoryStep::Redo);
+                    }
+                    ToolbarButton::Reseed => config.seed = thread_rng().gen(),
+                    ToolbarButton::CopyCode => {
+                        let code = encode_galaxy_code(&config);
+                        let message = match clipboard.get().and_then(|c| c.set_text(&code)) {
+                            Ok(()) => format!("Galaxy code copied: {code}"),
+                            Err(err) => format!("Couldn't copy the galaxy code ({err}): {code}"),
+                        };
+                        evw_notice.send(ShowNotice { message });
+                    }
+                    ToolbarButton::PasteCode => {
+                        let pasted = clipboard.get().and_then(|c| c.get_text());
+                        let message = match pasted {
+                            Ok(code) => match decode_galaxy_code(&code) {
+                                // -- Decoded codes are already validated
+                                Ok(pasted) => {
+                                    *config = pasted;
+                                    "Galaxy code loaded".to_string()
+                                }
+                                Err(err) => format!("Invalid galaxy code: {err}"),
+                            },
+                            Err(err) => format!("Couldn't read the clipboard: {err}"),
+                        };
+                        evw_notice.send(ShowNotice { message });
+                    }
+                    ToolbarButton::SurpriseMe | ToolbarButton::Nudge => {
+                        let updated = randomize(&config, &locks, *button, &mut thread_rng());
+                        apply_if_valid(&mut config, updated, &mut evw_notice);
+                    }
+                }
             }
-            Interaction::Hovered => {
-                *background_color = ELEMENT_BACKGROUND_COLOR_HOVERED.into();
+            Interaction::Hovered => *background_color = ELEMENT_BACKGROUND_COLOR_HOVERED,
+            Interaction::None => *background_color = ELEMENT_BACKGROUND_COLOR,
+        }
+    }
+}
+
+/// Samples every unlocked parameter, anywhere within its limits for
+/// "Surprise Me", around its current value for "Nudge"
+fn randomize(
+    config: &Configuration,
+    locks: &ParameterLocks,
+    button: ToolbarButton,
+    rng: &mut impl Rng,
+) -> Configuration {
+    let mut updated = config.clone();
+
+    for parameter in PARAMETERS.iter().filter(|parameter| !locks.is_locked(parameter)) {
+        let value = match button {
+            ToolbarButton::Nudge => parameter.sample_near(parameter.value(config), rng),
+            _ => parameter.sample(rng),
+        };
+        parameter.apply(&mut updated, value);
+    }
+
+    // -- A nudged galaxy keeps its seed so that it stays recognizable
+    if !matches!(button, ToolbarButton::Nudge) {
+        updated.seed = rng.gen();
+    }
+
+    updated
+}
+
+pub fn toggle_parameter_lock(
+    mut interaction_query: Query<
+        (&ParameterLock, &Interaction, &mut BackgroundColor, &Children),
+        Changed<Interaction>,
+    >,
+    mut marker_query: Query<&mut Visibility, With<Marker>>,
+    mut locks: ResMut<ParameterLocks>,
+) {
+    for (lock, interaction, mut background_color, children) in &mut interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let locked = locks.toggle(lock.parameter);
+        *background_color = if locked {
+            CHECKBOX_BACKGROUND_COLOR_CHECKED
+        } else {
+            ELEMENT_BACKGROUND_COLOR
+        };
+
+        let mut marker_iter = marker_query.iter_many_mut(children);
+        while let Some(mut visibility) = marker_iter.fetch_next() {
+            *visibility = if locked {
+                Visibility::Visible
+            } else {
+                Visibility::Hidden
+            };
+        }
+    }
+}
+
+pub fn drag_slider(
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    mut slider_query: Query<(&mut Slider, &Interaction, &Node, &GlobalTransform, &Children)>,
+    mut handle_query: Query<&mut Style, With<SliderHandle>>,
+    mut field_query: Query<(&NumberField, &mut TextInput)>,
+    mut evw_notice: EventWriter<ShowNotice>,
+    mut config: ResMut<Configuration>,
+) {
+    let cursor = q_window.get_single().ok().and_then(Window::cursor_position);
+
+    for (mut slider, interaction, node, transform, children) in &mut slider_query {
+        let parameter = slider.parameter;
+
+        match (*interaction, cursor) {
+            (Interaction::Pressed, Some(cursor)) => {
+                let rect = node.logical_rect(transform);
+                let fraction = ((cursor.x - rect.min.x) / rect.width()).clamp(0.0, 1.0);
+                let value =
+                    parameter.snap(parameter.min + fraction * (parameter.max - parameter.min));
+
+                slider.dragging = true;
+                if slider.value == value {
+                    continue;
+                }
+                slider.value = value;
+
+                let mut handle_iter = handle_query.iter_many_mut(children);
+                while let Some(mut style) = handle_iter.fetch_next() {
+                    style.left = Val::Percent(slider_percent(value, parameter));
+                }
+
+                // -- The number field previews the value being dragged to
+                let mut preview = config.clone();
+                parameter.apply(&mut preview, value);
+                for (field, mut input) in &mut field_query {
+                    if field.parameter == parameter {
+                        input.value = parameter.format(&preview);
+                    }
+                }
             }
-            Interaction::None => {
-                *background_color = ELEMENT_BACKGROUND_COLOR.into();
+            // -- Still held with the cursor out of the window, wait for it to come back
+            (Interaction::Pressed, None) => {}
+            _ if slider.dragging => {
+                // -- Regenerate only once the drag is released
+                slider.dragging = false;
+
+                let mut updated = config.clone();
+                parameter.apply(&mut updated, slider.value);
+                if apply_if_valid(&mut config, updated, &mut evw_notice) {
+                    continue;
+                }
+
+                // -- Rejected, back to the current value
+                slider.value = parameter.value(&config);
+                let mut handle_iter = handle_query.iter_many_mut(children);
+                while let Some(mut style) = handle_iter.fetch_next() {
+                    style.left = Val::Percent(slider_percent(slider.value, parameter));
+                }
+                for (field, mut input) in &mut field_query {
+                    if field.parameter == parameter {
+                        input.value = parameter.format(&config);
+                    }
+                }
             }
+            _ => {}
         }
     }
 }
 
+pub fn submit_number_field(
+    mut evr_submitted: EventReader<TextSubmitted>,
+    mut field_query: Query<(&NumberField, &mut TextInput)>,
+    mut focus: ResMut<InputFocus>,
+    mut evw_notice: EventWriter<ShowNotice>,
+    mut config: ResMut<Configuration>,
+) {
+    for ev in evr_submitted.read() {
+        let Ok((field, mut input)) = field_query.get_mut(ev.entity) else {
+            continue;
+        };
+
+        let parameter = field.parameter;
+        match parameter.parse(&input.value) {
+            // -- Typed values aren't clamped, the user is told the limits instead
+            Some(value) if !(parameter.min..=parameter.max).contains(&value) => {
+                let error = ConfigurationError::OutOfRange {
+                    parameter: parameter.name,
+                    value,
+                    min: parameter.min,
+                    max: parameter.max,
+                };
+                evw_notice.send(ShowNotice {
+                    message: format!("Not applied: {error}"),
+                });
+                input.value = parameter.format(&config);
+            }
+            Some(value) => {
+                let mut updated = config.clone();
+                parameter.apply(&mut updated, value);
+                apply_if_valid(&mut config, updated, &mut evw_notice);
+                input.value = parameter.format(&config);
+            }
+            None => input.value = parameter.format(&config),
+        }
+
+        focus.entity = None;
+    }
+}
+
+/// Replaces the configuration unless `updated` is invalid, in which case
+/// the user is told why. Returns whether `updated` is now the configuration.
+fn apply_if_valid(
+    config: &mut ResMut<Configuration>,
+    updated: Configuration,
+    evw_notice: &mut EventWriter<ShowNotice>,
+) -> bool {
+    if let Err(errors) = updated.validate() {
+        evw_notice.send(ShowNotice {
+            message: format!("Not applied: {errors}"),
+        });
+
+        return false;
+    }
+
+    // -- Unchanged values shouldn't regenerate the galaxy
+    config.set_if_neq(updated);
+
+    true
+}
+
+/// Fields left without pressing Enter go back to the current value
+pub fn revert_number_fields(
+    focus: Res<InputFocus>,
+    config: Res<Configuration>,
+    mut field_query: Query<(Entity, &NumberField, &mut TextInput)>,
+) {
+    for (entity, field, mut input) in &mut field_query {
+        let value = field.parameter.format(&config);
+
+        if focus.entity != Some(entity) && input.value != value {
+            input.value = value;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+
+    use super::*;
+
+    #[test]
+    fn locked_parameters_are_kept() {
+        let config = Configuration::default();
+        let mut locks = ParameterLocks::default();
+        locks.toggle(&PARAMETERS[0]);
+        locks.toggle(&PARAMETERS[3]);
+
+        let mut rng = StdRng::seed_from_u64(7);
+        for button in [ToolbarButton::SurpriseMe, ToolbarButton::Nudge] {
+            for _ in 0..20 {
+                let updated = randomize(&config, &locks, button, &mut rng);
+
+                assert_eq!(PARAMETERS[0].value(&updated), PARAMETERS[0].value(&config));
+                assert_eq!(PARAMETERS[3].value(&updated), PARAMETERS[3].value(&config));
+                assert!(updated.validate().is_ok(), "{updated:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn nudge_keeps_the_seed() {
+        let config = Configuration::default();
+        let locks = ParameterLocks::default();
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let nudged = randomize(&config, &locks, ToolbarButton::Nudge, &mut rng);
+        let surprise = randomize(&config, &locks, ToolbarButton::SurpriseMe, &mut rng);
+
+        assert_eq!(nudged.seed, config.seed);
+        assert_ne!(surprise.seed, config.seed);
+    }
+}
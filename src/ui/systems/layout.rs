@@ -1,12 +1,22 @@
 use bevy::prelude::*;
 
-use crate::configuration::resources::Configuration;
+use crate::configuration::parameters::*;
+use crate::configuration::resources::{Configuration, ParameterLocks};
+use crate::scene::grid::resources::GridSettings;
+use crate::scene::layers::LayerVisibility;
 use crate::ui::components::*;
 use crate::ui::styles::*;
+use crate::ui::systems::spawn_text_input;
+use crate::ui::UIState;
+
+const UI_TOGGLE_KEY: KeyCode = KeyCode::KeyI;
 
 pub fn build_ui(
     mut commands: Commands,
     config: Res<Configuration>,
+    grid_settings: Res<GridSettings>,
+    layers: Res<LayerVisibility>,
+    locks: Res<ParameterLocks>,
     asset_server: Res<AssetServer>,
 ) {
     let wrapper = NodeBundle {
@@ -15,13 +25,23 @@ pub fn build_ui(
     };
 
     commands.spawn(( wrapper, UI )).with_children(|parent| {
-        spawn_configuration(parent, &config, &asset_server);
+        spawn_configuration(
+            parent,
+            &config,
+            &grid_settings,
+            &layers,
+            &locks,
+            &asset_server,
+        );
     });
 }
 
 pub fn rebuild_ui(
     mut commands: Commands,
     config: Res<Configuration>,
+    grid_settings: Res<GridSettings>,
+    layers: Res<LayerVisibility>,
+    locks: Res<ParameterLocks>,
     asset_server: Res<AssetServer>,
     node_query: Query<Entity, With<UI>>,
 ) {
@@ -29,12 +49,42 @@ pub fn rebuild_ui(
         commands.entity(entity).despawn_recursive();
     }
 
-    build_ui(commands, config, asset_server);
+    build_ui(commands, config, grid_settings, layers, locks, asset_server);
+}
+
+pub fn toggle_ui(
+    kbd: Res<ButtonInput<KeyCode>>,
+    state: Res<State<UIState>>,
+    mut next_state: ResMut<NextState<UIState>>,
+) {
+    if kbd.just_pressed(UI_TOGGLE_KEY) {
+        next_state.set(match state.get() {
+            UIState::Displayed => UIState::Hidden,
+            UIState::Hidden => UIState::Displayed,
+        });
+    }
+}
+
+/// Keeps every panel and label out of sight, whatever shows them
+pub fn hide_ui(mut root_query: Query<&mut Visibility, (With<Node>, Without<Parent>)>) {
+    for mut visibility in &mut root_query {
+        visibility.set_if_neq(Visibility::Hidden);
+    }
+}
+
+/// Undoes `hide_ui`, map labels hide themselves again when out of view
+pub fn show_ui(mut root_query: Query<&mut Visibility, (With<Node>, Without<Parent>)>) {
+    for mut visibility in &mut root_query {
+        visibility.set_if_neq(Visibility::Inherited);
+    }
 }
 
 fn spawn_configuration(
     parent: &mut ChildBuilder,
     config: &Res<Configuration>,
+    grid_settings: &Res<GridSettings>,
+    layers: &Res<LayerVisibility>,
+    locks: &Res<ParameterLocks>,
     asset_server: &Res<AssetServer>,
 ) {
     let wrapper = NodeBundle {
@@ -64,27 +114,105 @@ fn spawn_configuration(
         ..default()
     };
 
-    parent.spawn(wrapper).with_children(|parent| {
+    // -- Interaction lets clicks on the panel be told apart from clicks on the map
+    parent.spawn((wrapper, Interaction::default())).with_children(|parent| {
         parent.spawn(title_wrapper).with_children(|parent| {
             parent.spawn(title);
         });
         parent.spawn(content_wrapper).with_children(|parent| {
-            spawn_checkbox_field(parent, config, asset_server, CheckboxKind::Dust);
-            spawn_checkbox_field(parent, config, asset_server, CheckboxKind::Filament);
-            spawn_checkbox_field(parent, config, asset_server, CheckboxKind::HII);
-            spawn_button_field(parent, config, asset_server, ButtonKind::StarCount);
-            spawn_button_field(parent, config, asset_server, ButtonKind::StarSize);
-            spawn_button_field(parent, config, asset_server, ButtonKind::Velocity);
-            spawn_button_field(parent, config, asset_server, ButtonKind::SemiAxis);
-            spawn_button_field(parent, config, asset_server, ButtonKind::OrbitRotation);
-            spawn_button_field(parent, config, asset_server, ButtonKind::OrbitDensity);
+            spawn_toolbar(parent, asset_server);
+            spawn_checkbox_field(
+                parent,
+                config,
+                grid_settings,
+                layers,
+                asset_server,
+                CheckboxKind::Dust,
+            );
+            spawn_checkbox_field(
+                parent,
+                config,
+                grid_settings,
+                layers,
+                asset_server,
+                CheckboxKind::Filament,
+            );
+            spawn_checkbox_field(
+                parent,
+                config,
+                grid_settings,
+                layers,
+                asset_server,
+                CheckboxKind::HII,
+            );
+            spawn_checkbox_field(
+                parent,
+                config,
+                grid_settings,
+                layers,
+                asset_server,
+                CheckboxKind::Grid,
+            );
+            for kind in [
+                CheckboxKind::ShowStars,
+                CheckboxKind::ShowDust,
+                CheckboxKind::ShowHII,
+                CheckboxKind::ShowFilaments,
+                CheckboxKind::ShowCenter,
+            ] {
+                spawn_checkbox_field(parent, config, grid_settings, layers, asset_server, kind);
+            }
+            for category in ParameterCategory::ALL {
+                spawn_category_title(parent, asset_server, category);
+                for parameter in PARAMETERS.iter().filter(|p| p.category == category) {
+                    spawn_parameter_field(parent, config, locks, asset_server, parameter);
+                }
+            }
         });
     });
 }
 
+fn spawn_toolbar(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>) {
+    let toolbar = NodeBundle {
+        style: TOOLBAR_STYLE,
+        ..default()
+    };
+
+    parent.spawn(toolbar).with_children(|parent| {
+        for button in [
+            ToolbarButton::Undo,
+            ToolbarButton::Redo,
+            ToolbarButton::Reseed,
+            ToolbarButton::CopyCode,
+            ToolbarButton::PasteCode,
+            ToolbarButton::SurpriseMe,
+            ToolbarButton::Nudge,
+        ] {
+            let node = NodeBundle {
+                style: TOOLBAR_BUTTON_STYLE,
+                border_color: UI_BORDER_COLOR,
+                border_radius: UI_BORDER_RADIUS,
+                background_color: ELEMENT_BACKGROUND_COLOR,
+                ..default()
+            };
+
+            parent
+                .spawn((node, button, Interaction::default()))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle::from_section(
+                        button.label(),
+                        get_text_style(asset_server, 18.0),
+                    ));
+                });
+        }
+    });
+}
+
 fn spawn_checkbox_field(
     parent: &mut ChildBuilder,
     config: &Res<Configuration>,
+    grid_settings: &Res<GridSettings>,
+    layers: &Res<LayerVisibility>,
     asset_server: &Res<AssetServer>,
     kind: CheckboxKind,
 ) {
@@ -104,6 +232,30 @@ fn spawn_checkbox_field(
             text = "H-II";
             checked = config.h2;
         }
+        CheckboxKind::Grid => {
+            text = "Grid";
+            checked = grid_settings.enabled;
+        }
+        CheckboxKind::ShowStars => {
+            text = "Show Stars";
+            checked = layers.stars;
+        }
+        CheckboxKind::ShowDust => {
+            text = "Show Dust";
+            checked = layers.dust;
+        }
+        CheckboxKind::ShowHII => {
+            text = "Show H-II";
+            checked = layers.hii;
+        }
+        CheckboxKind::ShowFilaments => {
+            text = "Show Filaments";
+            checked = layers.filaments;
+        }
+        CheckboxKind::ShowCenter => {
+            text = "Show Center";
+            checked = layers.center;
+        }
     }
 
     let wrapper = NodeBundle {
@@ -155,11 +307,23 @@ fn spawn_checkbox_field(
     });
 }
 
-fn spawn_button_field(
+fn spawn_category_title(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    category: ParameterCategory,
+) {
+    parent.spawn(TextBundle::from_section(
+        category.label(),
+        get_text_style(asset_server, 22.0),
+    ));
+}
+
+fn spawn_parameter_field(
     parent: &mut ChildBuilder,
     config: &Res<Configuration>,
+    locks: &Res<ParameterLocks>,
     asset_server: &Res<AssetServer>,
-    kind: ButtonKind,
+    parameter: &'static Parameter,
 ) {
     let wrapper = NodeBundle {
         style: FIELD_WRAPPER_STYLE,
@@ -168,98 +332,110 @@ fn spawn_button_field(
     let label = TextBundle {
         text: Text {
             sections: vec![TextSection {
-                value: kind.to_string(),
+                value: parameter.name.to_string(),
                 style: get_text_style(asset_server, 20.0),
             }],
             justify: JustifyText::Center,
             ..default()
         },
-        ..default()
-    };
-    let value = TextBundle {
-        text: Text {
-            sections: vec![TextSection {
-                value: config.map_to_button_value(kind),
-                style: get_text_style(asset_server, 14.0),
-            }],
-            justify: JustifyText::Right,
-            ..default()
-        },
-        style: BUTTON_VALUE_STYLE,
+        style: FIELD_LABEL_STYLE,
         ..default()
     };
 
     parent.spawn(wrapper).with_children(|parent| {
         parent.spawn(label);
-        parent.spawn(value);
-        spawn_button(parent, asset_server, kind);
+        spawn_text_input(
+            parent,
+            asset_server,
+            &parameter.format(config),
+            "",
+            NUMBER_FIELD_STYLE,
+            NumberField { parameter },
+        );
+        spawn_slider(parent, config, parameter);
+        spawn_lock(parent, asset_server, parameter, locks.is_locked(parameter));
     });
 }
 
-fn spawn_button(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>, kind: ButtonKind) {
-    let button_wrapper = NodeBundle {
-        style: BUTTON_WRAPPER_STYLE,
-        ..default()
-    };
-    let increment_button = NodeBundle {
-        style: BUTTON_STYLE,
+fn spawn_lock(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    parameter: &'static Parameter,
+    locked: bool,
+) {
+    let lock = NodeBundle {
+        style: LOCK_STYLE,
+        border_color: UI_BORDER_COLOR,
         border_radius: UI_BORDER_RADIUS,
-        background_color: ELEMENT_BACKGROUND_COLOR,
+        background_color: if locked {
+            CHECKBOX_BACKGROUND_COLOR_CHECKED
+        } else {
+            ELEMENT_BACKGROUND_COLOR
+        },
         ..default()
     };
-    let increment_button_text = TextBundle {
-        text: Text {
-            sections: vec![TextSection {
-                value: String::from("+"),
-                style: get_text_style(asset_server, 20.0),
-            }],
-            justify: JustifyText::Center,
-            ..default()
+    let marker = TextBundle {
+        visibility: if locked {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
         },
+        ..TextBundle::from_section("L", get_text_style(asset_server, 14.0))
+    };
+
+    parent
+        .spawn((lock, ParameterLock { parameter }, Interaction::default()))
+        .with_children(|parent| {
+            parent.spawn((marker, Marker));
+        });
+}
+
+fn spawn_slider(
+    parent: &mut ChildBuilder,
+    config: &Res<Configuration>,
+    parameter: &'static Parameter,
+) {
+    let value = parameter.value(config);
+
+    let slider = NodeBundle {
+        style: SLIDER_STYLE,
         ..default()
     };
-    let decrement_button = NodeBundle {
-        style: BUTTON_STYLE,
+    let track = NodeBundle {
+        style: SLIDER_TRACK_STYLE,
         border_radius: UI_BORDER_RADIUS,
-        background_color: ELEMENT_BACKGROUND_COLOR,
+        background_color: ELEMENT_BACKGROUND_COLOR_PRESSED,
         ..default()
     };
-    let decrement_button_text = TextBundle {
-        text: Text {
-            sections: vec![TextSection {
-                value: String::from("-"),
-                style: get_text_style(asset_server, 20.0),
-            }],
-            justify: JustifyText::Center,
-            ..default()
+    let handle = NodeBundle {
+        style: Style {
+            left: Val::Percent(slider_percent(value, parameter)),
+            ..SLIDER_HANDLE_STYLE
         },
+        border_radius: UI_BORDER_RADIUS,
+        background_color: SLIDER_HANDLE_COLOR,
         ..default()
     };
 
-    parent.spawn(button_wrapper).with_children(|parent| {
-        parent
-            .spawn((
-                increment_button,
-                TweakButton {
-                    action: ButtonAction::Increment,
-                    kind,
-                },
-                Interaction::default(),
-            ))
-            .with_children(|parent| {
-                parent.spawn(increment_button_text);
-            });
-        parent
-            .spawn((
-                decrement_button,
-                TweakButton {
-                    action: ButtonAction::Decrement,
-                    kind,
-                },
-                Interaction::default(),
-            ))
-            .with_children(|parent| {
-                parent.spawn(decrement_button_text);
-            });
-    });
+    parent
+        .spawn((
+            slider,
+            Slider {
+                parameter,
+                value,
+                dragging: false,
+            },
+            Interaction::default(),
+        ))
+        .with_children(|parent| {
+            parent.spawn(track);
+            parent.spawn((handle, SliderHandle));
+        });
+}
+
+/// Position of a value along a slider, in percent of its width
+pub fn slider_percent(value: f32, parameter: &Parameter) -> f32 {
+    let fraction = (value - parameter.min) / (parameter.max - parameter.min);
+
+    fraction.clamp(0.0, 1.0) * 100.0
 }
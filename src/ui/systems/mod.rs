@@ -1,5 +1,9 @@
 pub mod layout;
 pub mod interactions;
+pub mod notice;
+pub mod text_input;
 
 pub use layout::*;
 pub use interactions::*;
+pub use notice::*;
+pub use text_input::*;
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+use crate::ui::components::Notice;
+use crate::ui::events::ShowNotice;
+use crate::ui::styles::*;
+
+/// How long a notice stays on screen
+const NOTICE_SECONDS: f32 = 5.0;
+
+pub fn show_notice(
+    mut commands: Commands,
+    mut evr_notice: EventReader<ShowNotice>,
+    asset_server: Res<AssetServer>,
+    notice_query: Query<Entity, With<Notice>>,
+) {
+    // -- Only the latest notice is shown
+    let Some(notice) = evr_notice.read().last() else {
+        return;
+    };
+
+    for entity in &notice_query {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    let wrapper = NodeBundle {
+        style: NOTICE_STYLE,
+        background_color: UI_BACKGROUND_COLOR,
+        border_radius: UI_BORDER_RADIUS,
+        ..default()
+    };
+    let text = TextBundle::from_section(&notice.message, get_text_style(&asset_server, 18.0));
+
+    commands
+        .spawn((
+            wrapper,
+            Notice {
+                timer: Timer::from_seconds(NOTICE_SECONDS, TimerMode::Once),
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn(text);
+        });
+}
+
+pub fn expire_notice(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut notice_query: Query<(Entity, &mut Notice)>,
+) {
+    for (entity, mut notice) in &mut notice_query {
+        if notice.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
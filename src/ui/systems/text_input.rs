@@ -0,0 +1,145 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+
+use crate::ui::components::*;
+use crate::ui::events::TextSubmitted;
+use crate::ui::resources::InputFocus;
+use crate::ui::styles::*;
+
+const TEXT_INPUT_CARET: &str = "|";
+
+/// Clicking a text input focuses it, clicking anywhere else drops the focus
+pub fn focus_text_input(
+    ms: Res<ButtonInput<MouseButton>>,
+    input_query: Query<(Entity, &Interaction), With<TextInput>>,
+    mut focus: ResMut<InputFocus>,
+) {
+    if !ms.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    let clicked = input_query
+        .iter()
+        .find(|(_, interaction)| **interaction == Interaction::Pressed)
+        .map(|(entity, _)| entity);
+
+    if focus.entity != clicked {
+        focus.entity = clicked;
+    }
+}
+
+pub fn type_into_text_input(
+    mut evr_keys: EventReader<KeyboardInput>,
+    mut focus: ResMut<InputFocus>,
+    mut input_query: Query<&mut TextInput>,
+    mut evw_submitted: EventWriter<TextSubmitted>,
+) {
+    let Some(entity) = focus.entity else {
+        evr_keys.clear();
+        return;
+    };
+    let Ok(mut input) = input_query.get_mut(entity) else {
+        focus.entity = None;
+        return;
+    };
+
+    for ev in evr_keys.read() {
+        if ev.state != ButtonState::Pressed {
+            continue;
+        }
+
+        match &ev.logical_key {
+            Key::Character(characters) => {
+                let characters = characters.chars().filter(|c| !c.is_control());
+                input.value.extend(characters);
+            }
+            Key::Space => input.value.push(' '),
+            Key::Backspace => {
+                input.value.pop();
+            }
+            Key::Enter => {
+                evw_submitted.send(TextSubmitted { entity });
+            }
+            Key::Escape => {
+                focus.entity = None;
+                break;
+            }
+            _ => {}
+        }
+    }
+}
+
+pub fn display_text_input(
+    focus: Res<InputFocus>,
+    mut input_query: Query<(Entity, Ref<TextInput>, &Children, &mut BorderColor)>,
+    mut text_query: Query<&mut Text, With<TextInputText>>,
+) {
+    for (entity, input, children, mut border_color) in &mut input_query {
+        if !input.is_changed() && !focus.is_changed() {
+            continue;
+        }
+
+        let focused = focus.entity == Some(entity);
+        *border_color = if focused {
+            CHECKBOX_BORDER_COLOR_CHECKED
+        } else {
+            TEXT_INPUT_BORDER_COLOR
+        };
+
+        let mut text_iter = text_query.iter_many_mut(children);
+        while let Some(mut text) = text_iter.fetch_next() {
+            let section = &mut text.sections[0];
+
+            if input.value.is_empty() && !focused {
+                section.value = input.placeholder.clone();
+                section.style.color = TEXT_INPUT_PLACEHOLDER_COLOR;
+            } else {
+                section.value = input.value.clone();
+                section.style.color = Color::WHITE;
+                if focused {
+                    section.value.push_str(TEXT_INPUT_CARET);
+                }
+            }
+        }
+    }
+}
+
+/// Keeps typed letters from also triggering the keyboard shortcuts
+pub fn block_shortcuts_while_typing(focus: Res<InputFocus>, mut kbd: ResMut<ButtonInput<KeyCode>>) {
+    if focus.entity.is_some() {
+        kbd.reset_all();
+    }
+}
+
+/// Spawns a text input with its text child and returns the input's entity
+pub fn spawn_text_input(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    value: &str,
+    placeholder: &str,
+    style: Style,
+    bundle: impl Bundle,
+) -> Entity {
+    let field = NodeBundle {
+        style,
+        border_color: TEXT_INPUT_BORDER_COLOR,
+        border_radius: UI_BORDER_RADIUS,
+        background_color: ELEMENT_BACKGROUND_COLOR,
+        ..default()
+    };
+    // -- The text is filled in by `display_text_input`
+    let text = TextBundle::from_section("", get_text_style(asset_server, 16.0));
+
+    let input = TextInput {
+        value: value.to_string(),
+        placeholder: placeholder.to_string(),
+    };
+
+    parent
+        .spawn((field, input, Interaction::default(), bundle))
+        .with_children(|parent| {
+            parent.spawn((text, TextInputText));
+        })
+        .id()
+}